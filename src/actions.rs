@@ -0,0 +1,214 @@
+use tokens::*;
+
+use utility::*;
+use construction;
+use sailing::{self, Voyage};
+use cartography;
+use claims;
+use terraforming;
+use ecology;
+
+// trait actions, voyages, actions offered by the region, blockades around and waiting.
+pub fn available(explorer: &Explorer, region: &Region, regions: &SelectionHashMap<Region>) -> Vec<ExplorerAction> {
+    let mut actions = explorer.trait_actions();
+
+    if explorer.has_trait(&ExplorerTrait::Sailor) {
+        if let Some(vessel) = sailing::vessel(explorer, region) {
+            for destination in sailing::destinations(regions, region.id, vessel) {
+                actions.push(ExplorerAction::Sail(destination));
+            }
+        }
+    }
+
+    for equipment in gatherable(region) {
+        actions.push(ExplorerAction::Gather(equipment));
+    }
+
+    for blockade in claims::blockades(region, regions) {
+        actions.push(ExplorerAction::Negotiate(blockade));
+        actions.push(ExplorerAction::Dismantle(blockade));
+        actions.push(ExplorerAction::Bypass(blockade));
+    }
+    if region.owner != Some(Faction::Expedition) {
+        actions.push(ExplorerAction::Claim);
+    }
+
+    for modification in terraforming::possible(region, regions) {
+        actions.push(ExplorerAction::Modify(modification));
+    }
+
+    actions.push(ExplorerAction::Wait);
+    actions
+}
+
+// what the region offers to anyone willing to collect it
+pub fn gatherable(region: &Region) -> Vec<Equipment> {
+    let mut equipment = vec![];
+
+    if region.terrain == Terrain::Forest || region.has_particularity(Particularity::Grove) {
+        equipment.push(Equipment::Firewood(1));
+    }
+    if region.has_particularity(Particularity::Clearing) || region.has_particularity(Particularity::Grasland) {
+        equipment.push(Equipment::Herbs(1));
+    }
+    if [Particularity::Lake, Particularity::Pond, Particularity::River, Particularity::Creek].iter().any(|water| region.has_particularity(*water)) {
+        equipment.push(Equipment::Food(1));
+    }
+
+    equipment
+}
+
+pub fn description(action: &ExplorerAction) -> String {
+    match action {
+        ExplorerAction::Build(building) => format!("work on a {:?}, taking {} turns in total.", building, building.turns()),
+        ExplorerAction::Hunt => "hunt for game in the region.".to_string(),
+        ExplorerAction::Gather(_) => "collect what the region has to offer.".to_string(),
+        ExplorerAction::Sail(destination) => format!("set sail for region {}, taking others in the region along.", destination),
+        ExplorerAction::Map => "survey the region and its surroundings.".to_string(),
+        ExplorerAction::Negotiate(region) => format!("pay to have the blockade at region {} lifted.", region),
+        ExplorerAction::Dismantle(region) => format!("tear down the blockade at region {} piece by piece.", region),
+        ExplorerAction::Bypass(region) => format!("sneak past the blockade into region {}.", region),
+        ExplorerAction::Claim => "claim the region for the expedition.".to_string(),
+        ExplorerAction::Modify(Modification::DigChannel(region)) => format!("lead the river into region {}, creeks there swell to a river and the river below runs dry.", region),
+        ExplorerAction::Modify(Modification::Dam) => "close the dam, the river below runs dry.".to_string(),
+        ExplorerAction::Modify(Modification::ClearBoulders) => "haul the boulders away.".to_string(),
+        ExplorerAction::Modify(Modification::FellGrove) => "cut down the grove for firewood.".to_string(),
+        ExplorerAction::Wait => "let time pass.".to_string()
+    }
+}
+
+pub fn costs(action: &ExplorerAction, region: &Region) -> String {
+    match action {
+        ExplorerAction::Build(building) => {
+            if region.buildings.iter().any(|construction| construction.building == *building) {
+                "nothing, construction has started.".to_string()
+            } else {
+                format!("{:?}", building.costs())
+            }
+        },
+        ExplorerAction::Hunt | ExplorerAction::Gather(_) => "1 resource of the region.".to_string(),
+        ExplorerAction::Map => format!("{:?}", Equipment::Parchment(1)),
+        ExplorerAction::Dismantle(_) => "a turn of work.".to_string(),
+        ExplorerAction::Negotiate(_) => "the toll asked at the blockade.".to_string(),
+        ExplorerAction::Modify(_) => "a turn of hard work.".to_string(),
+        ExplorerAction::Sail(_) | ExplorerAction::Bypass(_) | ExplorerAction::Claim | ExplorerAction::Wait => "nothing.".to_string()
+    }
+}
+
+pub fn preview(action: &ExplorerAction, explorer: &Explorer, region: &Region, regions: &SelectionHashMap<Region>) -> String {
+    if let ExplorerAction::Sail(destination) = action {
+        return match sailing::seafaring(explorer, regions, *destination) {
+            Ok((vessel, chance)) => format!("{:?} with room for {}, {}% to arrive.", vessel, vessel.passengers(), chance),
+            Err(reason) => reason
+        };
+    }
+
+    if let ExplorerAction::Map = action {
+        return cartography::preview(explorer, regions);
+    }
+
+    if claims::target(action).is_some() {
+        return claims::preview(action, explorer, regions);
+    }
+
+    if let ExplorerAction::Modify(modification) = action {
+        return terraforming::preview(*modification, explorer, region, regions);
+    }
+
+    let mut explorer = explorer.clone();
+    let mut region = region.clone();
+
+    match on_region(action, &mut explorer, &mut region) {
+        Ok(()) => {
+            match action {
+                ExplorerAction::Build(building) => {
+                    match region.buildings.iter().find(|construction| construction.building == *building) {
+                        Some(construction) => format!("{}", construction),
+                        None => String::new()
+                    }
+                },
+                ExplorerAction::Hunt => format!("{:?}, {} resources left.", hunt_yield(), region.resources),
+                ExplorerAction::Gather(equipment) => format!("{:?}, {} resources left.", equipment, region.resources),
+                ExplorerAction::Claim => "the region belongs to the expedition.".to_string(),
+                _ => "nothing happens.".to_string()
+            }
+        },
+        Err(reason) => reason
+    }
+}
+
+fn hunt_yield() -> Equipment {
+    Equipment::Food(2)
+}
+
+fn deplete(region: &mut Region) -> Result<(), String> {
+    if region.resources > 0 {
+        region.resources -= 1;
+        ecology::exploit(region);
+        Ok(())
+    } else {
+        Err("the region is depleted.".to_string())
+    }
+}
+
+// carries out the action of the explorer in the region, on the regions of the template so every later state keeps the outcome
+pub fn act(action: &ExplorerAction, explorer: ExplorerId, region: RegionId, explorers: &mut SelectionStorage<Explorer>, regions: &mut SelectionHashMap<Region>, age: usize) -> Result<(), String> {
+    // a voyage moves the passengers as well
+    if let ExplorerAction::Sail(destination) = *action {
+        return match sailing::sail(explorer, destination, explorers, regions, age)? {
+            Voyage::Arrived => Ok(()),
+            Voyage::TurnedBack => Err("the sea turned the voyage back.".to_string())
+        };
+    }
+
+    let explorer = match explorers.storage_mut().get_mut(explorer) {
+        Some(explorer) => explorer,
+        None => return Err("there is no such explorer.".to_string())
+    };
+    let outcome = match *action {
+        // maps survey the surroundings as well
+        ExplorerAction::Map => cartography::map(explorer, regions, age),
+        // blockades stand in the regions around, bypassing them moves the explorer
+        ExplorerAction::Negotiate(_) | ExplorerAction::Dismantle(_) | ExplorerAction::Bypass(_) => return claims::resolve(action, explorer, regions, age),
+        // modifications reach into the regions around
+        ExplorerAction::Modify(modification) => return terraforming::modify(modification, explorer, region, regions),
+        _ => match regions.storage_mut().get_mut(&region) {
+            Some(region) => on_region(action, explorer, region),
+            None => Err("there is no such region.".to_string())
+        }
+    };
+    if outcome.is_ok() {
+        explorer.note_action(action);
+    }
+    outcome
+}
+
+fn on_region(action: &ExplorerAction, explorer: &mut Explorer, region: &mut Region) -> Result<(), String> {
+    match action {
+        ExplorerAction::Build(building) => {
+            construction::build(*building, explorer, region)
+        },
+        ExplorerAction::Hunt => {
+            deplete(region)?;
+            explorer.add_equipment(hunt_yield());
+            explorer.note_found(&ExplorerItem::Equipment(hunt_yield()));
+            Ok(())
+        },
+        ExplorerAction::Gather(equipment) => {
+            if !gatherable(region).contains(equipment) {
+                return Err(format!("there is no {:?} to be found here.", equipment));
+            }
+            deplete(region)?;
+            explorer.add_equipment(*equipment);
+            explorer.note_found(&ExplorerItem::Equipment(*equipment));
+            Ok(())
+        },
+        ExplorerAction::Claim => {
+            claims::claimable(explorer, region)?;
+            region.owner = Some(Faction::Expedition);
+            Ok(())
+        },
+        ExplorerAction::Wait => Ok(()),
+        _ => Err(format!("{} is not an action on the region alone.", action))
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt;
+
+use tokens::*;
+use client_api::RealmsClient;
+
+mod tactics;
+mod scout;
+mod settler;
+
+// orders a bot gives before it leaves its realm unfinished
+pub const ORDERS: usize = 500;
+
+// how a bot goes about its realm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BotVariant {
+    Scout,
+    Settler
+}
+
+impl BotVariant {
+    pub fn all() -> Vec<BotVariant> {
+        vec![BotVariant::Scout, BotVariant::Settler]
+    }
+
+    pub fn strategy(self) -> Box<dyn Strategy> {
+        match self {
+            BotVariant::Scout => Box::new(scout::Scout),
+            BotVariant::Settler => Box::new(settler::Settler)
+        }
+    }
+}
+
+// decides what each explorer is ordered to do
+pub trait Strategy {
+    // orders worth giving the explorer, the most pressing first
+    fn orders(&self, realm: &Realm, explorer: &Explorer) -> Vec<RealmsProtocol>;
+}
+
+// plays a realm as any client would, one order at a time
+pub struct Bot {
+    pub id: usize,
+    pub variant: BotVariant,
+    strategy: Box<dyn Strategy>,
+    // explorers take turns receiving orders
+    turn: usize,
+    // orders given since the realm last aged, not given again until it does
+    given: Vec<String>,
+    age: usize
+}
+
+impl Bot {
+    pub fn new(id: usize, variant: BotVariant) -> Bot {
+        Bot {
+            id,
+            variant,
+            strategy: variant.strategy(),
+            turn: 0,
+            given: vec![],
+            age: 0
+        }
+    }
+
+    // asks for the realm again when no explorer has anything left to do
+    pub fn order(&mut self, realm: &Realm) -> RealmsProtocol {
+        if realm.age != self.age {
+            self.given.clear();
+            self.age = realm.age;
+        }
+
+        let explorers = realm.expedition.explorers.storage();
+        for offset in 0..explorers.len() {
+            let explorer = &explorers[(self.turn + offset) % explorers.len()];
+            let order = self.strategy.orders(realm, explorer).into_iter()
+                .find(|order| !self.given.contains(&format!("{}", order)));
+            if let Some(order) = order {
+                self.turn += offset + 1;
+                self.given.push(format!("{}", order));
+                return order;
+            }
+        }
+
+        // waiting lets the realm age, which makes the orders worth another try
+        self.turn += 1;
+        match explorers.iter().filter_map(|explorer| explorer.region.map(|region| (explorer.id, region))).next() {
+            Some((explorer, region)) => RealmsProtocol::Explorer(Move::Action(realm.id, region, explorer, ExplorerAction::Wait)),
+            None => RealmsProtocol::RequestRealm(realm.id)
+        }
+    }
+}
+
+// how far a bot got with its realm
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub bot: usize,
+    pub variant: BotVariant,
+    pub realm: RealmId,
+    pub age: usize,
+    pub orders: usize,
+    pub rejected: usize,
+    pub completed: usize,
+    pub objectives: usize,
+    pub done: bool
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bot {} ({:?}) realm {}: {}/{} objectives at age {}, {} orders, {} rejected{}",
+            self.bot, self.variant, self.realm, self.completed, self.objectives, self.age, self.orders, self.rejected,
+            if self.done { ", done" } else { "" })
+    }
+}
+
+// starts a new realm and plays it until it is done or the orders run out
+pub fn play(client: &mut RealmsClient, bot: &mut Bot, orders: usize) -> Result<Outcome, String> {
+    let mut realm = client.new_realm()?;
+    let mut given = 0;
+    let mut rejected = 0;
+
+    while given < orders && !realm.done {
+        let order = bot.order(&realm);
+        given += 1;
+        match client.send(order)? {
+            RealmsProtocol::Realm(answer) => realm = answer,
+            RealmsProtocol::Rejected(_) => rejected += 1,
+            response => return Err(format!("the server answered {}.", response))
+        }
+    }
+
+    Ok(Outcome {
+        bot: bot.id,
+        variant: bot.variant,
+        realm: realm.id,
+        age: realm.age,
+        orders: given,
+        rejected,
+        completed: realm.completed.len(),
+        objectives: realm.objectives.len(),
+        done: realm.done
+    })
+}
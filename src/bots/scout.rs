@@ -0,0 +1,17 @@
+use tokens::*;
+
+use super::{tactics, Strategy};
+
+// goes ashore, keeps the expedition fed and walks off into the unknown
+pub struct Scout;
+
+impl Strategy for Scout {
+    fn orders(&self, realm: &Realm, explorer: &Explorer) -> Vec<RealmsProtocol> {
+        let mut orders = vec![];
+        orders.extend(tactics::embark(realm, explorer));
+        orders.extend(tactics::pick(realm, explorer));
+        orders.extend(tactics::hunt(realm, explorer));
+        orders.extend(tactics::explore(realm, explorer));
+        orders
+    }
+}
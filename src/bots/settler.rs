@@ -0,0 +1,50 @@
+use tokens::*;
+
+use super::{tactics, Strategy};
+
+// goes after the objectives of the realm in turn and only explores when none of them can be worked on
+pub struct Settler;
+
+impl Strategy for Settler {
+    fn orders(&self, realm: &Realm, explorer: &Explorer) -> Vec<RealmsProtocol> {
+        let mut orders = vec![];
+        orders.extend(tactics::embark(realm, explorer));
+        orders.extend(tactics::report(realm, explorer));
+
+        if tactics::open(realm, |objective| *objective == RealmObjective::BuildWell) {
+            orders.extend(well(realm, explorer));
+        }
+        if tactics::open(realm, |objective| matches!(objective, RealmObjective::ClaimRegions(_))) {
+            orders.extend(tactics::claim(realm, explorer));
+        }
+
+        orders.extend(tactics::investigate(realm, explorer));
+        orders.extend(tactics::pick(realm, explorer));
+        orders.extend(tactics::hunt(realm, explorer));
+        orders.extend(tactics::explore(realm, explorer));
+        orders
+    }
+}
+
+// the builder heads to the farmers and builds, everyone else brings the materials
+fn well(realm: &Realm, explorer: &Explorer) -> Vec<RealmsProtocol> {
+    let farmers = realm.island.regions.iter()
+        .map(|(_, region)| region)
+        .find(|region| region.has_particularity(Particularity::Farmers));
+    let farmers = match farmers {
+        Some(farmers) => farmers.id,
+        None => return vec![]
+    };
+
+    let mut orders = vec![];
+    if tactics::builder(explorer) {
+        if explorer.region == Some(farmers) {
+            orders.extend(tactics::build(realm, explorer, Building::Well));
+        } else if Building::Well.costs().iter().all(|cost| explorer.has_equipment(cost)) {
+            orders.extend(tactics::head_to(realm, explorer, farmers));
+        }
+    } else {
+        orders.extend(tactics::supply(realm, explorer, Building::Well));
+    }
+    orders
+}
@@ -0,0 +1,180 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use tokens::*;
+use actions;
+use browser;
+use claims;
+use construction;
+use investigation;
+
+// below this much food in the whole expedition the bots go hunting
+const FOOD_LOW: usize = 6;
+
+pub fn region<'a>(realm: &'a Realm, explorer: &Explorer) -> Option<&'a Region> {
+    explorer.region.and_then(|id| realm.island.regions.storage().get(&id))
+}
+
+// the objective is part of the realm and not completed yet
+pub fn open<F: Fn(&RealmObjective) -> bool>(realm: &Realm, objective: F) -> bool {
+    realm.objectives.iter().any(|candidate| objective(candidate) && !realm.completed.contains(candidate))
+}
+
+fn act(realm: &Realm, region: &Region, explorer: &Explorer, action: ExplorerAction) -> RealmsProtocol {
+    RealmsProtocol::Explorer(Move::Action(realm.id, region.id, explorer.id, action))
+}
+
+fn change_region(realm: &Realm, explorer: &Explorer, region: RegionId) -> RealmsProtocol {
+    RealmsProtocol::Explorer(Move::ChangeRegion(realm.id, region, explorer.id))
+}
+
+// explorers still on the ship go ashore at the coast, any known region does if there is none
+pub fn embark(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    if explorer.region.is_some() {
+        return None;
+    }
+    let mut known: Vec<&Region> = realm.island.regions.iter().map(|(_, region)| region).collect();
+    known.sort_by_key(|region| (region.terrain != Terrain::Coast, region.id));
+    known.first().map(|region| change_region(realm, explorer, region.id))
+}
+
+// picks up equipment the explorer does not carry yet, stacks are always worth taking
+pub fn pick(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    let region = region(realm, explorer)?;
+    region.particularities.iter()
+        .filter_map(|particularity| match particularity {
+            Particularity::Item(equipment) => Some(*equipment),
+            _ => None
+        })
+        .find(|equipment| equipment.amount().is_some() || !explorer.has_equipment(equipment))
+        .map(|equipment| RealmsProtocol::PickEquipment(realm.id, region.id, explorer.id, equipment))
+}
+
+pub fn food(realm: &Realm) -> usize {
+    realm.expedition.explorers.iter()
+        .flat_map(|explorer| explorer.inventory.iter())
+        .filter_map(|item| match item {
+            ExplorerItem::Equipment(Equipment::Food(amount)) => Some(*amount),
+            _ => None
+        })
+        .sum()
+}
+
+// rangers hunt and everyone else fishes while the food runs low
+pub fn hunt(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    let region = region(realm, explorer)?;
+    if food(realm) >= FOOD_LOW {
+        return None;
+    }
+    let available = actions::available(explorer, region, &realm.island.regions);
+    let hunting = available.iter().any(|action| matches!(action, ExplorerAction::Hunt));
+    let fishing = available.iter().any(|action| matches!(action, ExplorerAction::Gather(Equipment::Food(_))));
+
+    if hunting && region.resources > 0 {
+        Some(act(realm, region, explorer, ExplorerAction::Hunt))
+    } else if fishing {
+        Some(act(realm, region, explorer, ExplorerAction::Gather(Equipment::Food(1))))
+    } else {
+        None
+    }
+}
+
+// the first step on the shortest way over known regions, none if the explorer is there or it cannot be reached
+pub fn head_to(realm: &Realm, explorer: &Explorer, target: RegionId) -> Option<RealmsProtocol> {
+    let from = explorer.region?;
+    let steps = steps(realm, explorer, from);
+    if from == target || !steps.contains_key(&target) {
+        return None;
+    }
+    let mut region = target;
+    while steps[&region] != from {
+        region = steps[&region];
+    }
+    Some(change_region(realm, explorer, region))
+}
+
+// the region each reachable region is entered from, blockades and rivers the explorer cannot cross are in the way
+fn steps(realm: &Realm, explorer: &Explorer, from: RegionId) -> BTreeMap<RegionId, RegionId> {
+    let regions = realm.island.regions.storage();
+    let mut steps = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(from);
+
+    while let Some(id) = queue.pop_front() {
+        if let Some(region) = regions.get(&id) {
+            for neighbor in &region.neighbors {
+                let passable = regions.get(neighbor)
+                    .map(|neighbor| neighbor.blockade.is_none() && construction::river_crossing(explorer, Some(region), neighbor))
+                    .unwrap_or(false);
+                if passable && *neighbor != from && !steps.contains_key(neighbor) {
+                    steps.insert(*neighbor, id);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+    }
+
+    steps
+}
+
+// walks towards the closest region no explorer has been to, or else the closest one this explorer has not been to
+pub fn explore(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    let from = explorer.region?;
+    let visited = |explorer: &Explorer, id: RegionId| explorer.trail.iter().any(|mark| mark.region == id);
+    let mut targets: Vec<(bool, usize, RegionId)> = browser::distances(&realm.island.regions, from).into_iter()
+        .filter(|(id, _)| *id != from && realm.island.regions.storage().contains_key(id) && !visited(explorer, *id))
+        .map(|(id, distance)| (realm.expedition.explorers.iter().any(|other| visited(other, id)), distance, id))
+        .collect();
+    targets.sort();
+    targets.iter().filter_map(|(_, _, target)| head_to(realm, explorer, *target)).next()
+}
+
+pub fn report(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    investigation::reports(explorer, &realm.characters).first()
+        .map(|(character, finding)| RealmsProtocol::Report(realm.id, explorer.id, *character, *finding))
+}
+
+// investigates a particularity still holding clues the explorer lacks
+pub fn investigate(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    let region = region(realm, explorer)?;
+    region.particularities.iter()
+        .filter(|particularity| !matches!(particularity, Particularity::Item(_)))
+        .find(|particularity| investigation::clues(explorer, region, **particularity, &realm.island.regions).map(|clues| !clues.is_empty()).unwrap_or(false))
+        .map(|particularity| RealmsProtocol::InvestigateParticularity(realm.id, region.id, explorer.id, *particularity))
+}
+
+pub fn claim(realm: &Realm, explorer: &Explorer) -> Option<RealmsProtocol> {
+    let region = region(realm, explorer)?;
+    claims::claimable(explorer, region).ok().map(|_| act(realm, region, explorer, ExplorerAction::Claim))
+}
+
+pub fn build(realm: &Realm, explorer: &Explorer, building: Building) -> Option<RealmsProtocol> {
+    let region = region(realm, explorer)?;
+    construction::buildable(building, explorer, region).ok().map(|_| act(realm, region, explorer, ExplorerAction::Build(building)))
+}
+
+pub fn builder(explorer: &Explorer) -> bool {
+    explorer.has_trait(&ExplorerTrait::Builder) && explorer.has_equipment(&Equipment::Tools)
+}
+
+// materials for the building the builder lacks and the explorer carries
+pub fn missing(building: Building, explorer: &Explorer, builder: &Explorer) -> Vec<ExplorerItem> {
+    building.costs().iter()
+        .filter(|cost| !builder.has_equipment(cost) && explorer.has_equipment(cost))
+        .filter_map(|cost| explorer.inventory.iter().find(|item| match item {
+            ExplorerItem::Equipment(carried) => carried.same_kind(cost) && carried.amount() >= cost.amount(),
+            _ => false
+        }))
+        .cloned()
+        .collect()
+}
+
+// hands the builder what it lacks for the building, walking over to it first
+pub fn supply(realm: &Realm, explorer: &Explorer, building: Building) -> Option<RealmsProtocol> {
+    let builder = realm.expedition.explorers.iter().find(|candidate| candidate.id != explorer.id && builder(candidate) && candidate.region.is_some())?;
+    let item = missing(building, explorer, builder).into_iter().next()?;
+    if builder.region == explorer.region {
+        Some(RealmsProtocol::GiveItem(realm.id, explorer.id, builder.id, item))
+    } else {
+        head_to(realm, explorer, builder.region?)
+    }
+}
@@ -0,0 +1,114 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, VecDeque};
+
+use tokens::*;
+use utility::*;
+
+// order of the regions tab, switched through in turn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegionSort {
+	Id,
+	Terrain,
+	Sight,
+	Resources,
+	Distance
+}
+
+impl RegionSort {
+	pub fn next(self) -> RegionSort {
+		match self {
+			RegionSort::Id => RegionSort::Terrain,
+			RegionSort::Terrain => RegionSort::Sight,
+			RegionSort::Sight => RegionSort::Resources,
+			RegionSort::Resources => RegionSort::Distance,
+			RegionSort::Distance => RegionSort::Id
+		}
+	}
+}
+
+// which regions the regions tab lists, switched through in turn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegionFilter {
+	All,
+	InView,
+	Remembered,
+	Mapped,
+	Occupied
+}
+
+impl RegionFilter {
+	pub fn next(self) -> RegionFilter {
+		match self {
+			RegionFilter::All => RegionFilter::InView,
+			RegionFilter::InView => RegionFilter::Remembered,
+			RegionFilter::Remembered => RegionFilter::Mapped,
+			RegionFilter::Mapped => RegionFilter::Occupied,
+			RegionFilter::Occupied => RegionFilter::All
+		}
+	}
+
+	fn admits(self, region: &Region, explorers: &SelectionStorage<Explorer>) -> bool {
+		match self {
+			RegionFilter::All => true,
+			RegionFilter::InView => region.sight >= RegionVisibility::Partial,
+			RegionFilter::Remembered => region.sight == RegionVisibility::Remembered,
+			RegionFilter::Mapped => region.mapped.is_some(),
+			RegionFilter::Occupied => !occupants(region.id, explorers).is_empty()
+		}
+	}
+}
+
+// explorers standing in the region
+pub fn occupants(region: RegionId, explorers: &SelectionStorage<Explorer>) -> Vec<&Explorer> {
+	explorers.iter().filter(|explorer| explorer.region == Some(region)).collect()
+}
+
+// resources are only counted where the expedition can look closely
+pub fn resources(region: &Region) -> Option<usize> {
+	if region.sight >= RegionVisibility::Complete {
+		Some(region.resources)
+	} else {
+		None
+	}
+}
+
+// steps over neighboring regions from the given one to every region that can be reached
+pub fn distances(regions: &SelectionHashMap<Region>, from: RegionId) -> BTreeMap<RegionId, usize> {
+	let mut distances = BTreeMap::new();
+	let mut queue = VecDeque::new();
+	distances.insert(from, 0);
+	queue.push_back(from);
+
+	while let Some(id) = queue.pop_front() {
+		let steps = distances[&id];
+		if let Some(region) = regions.storage().get(&id) {
+			for neighbor in &region.neighbors {
+				if !distances.contains_key(neighbor) {
+					distances.insert(*neighbor, steps + 1);
+					queue.push_back(*neighbor);
+				}
+			}
+		}
+	}
+
+	distances
+}
+
+// ids of the regions the tab lists, in the order it lists them
+pub fn browse(regions: &SelectionHashMap<Region>, explorers: &SelectionStorage<Explorer>, distances: &BTreeMap<RegionId, usize>, sort: RegionSort, filter: RegionFilter) -> Vec<RegionId> {
+	let mut listed: Vec<&Region> = regions.iter()
+		.map(|(_, region)| region)
+		.filter(|region| filter.admits(region, explorers))
+		.collect();
+
+	// ties keep the order of the ids
+	match sort {
+		RegionSort::Id => { },
+		RegionSort::Terrain => listed.sort_by_key(|region| format!("{:?}", region.terrain)),
+		RegionSort::Sight => listed.sort_by_key(|region| Reverse(region.sight.clone())),
+		RegionSort::Resources => listed.sort_by_key(|region| Reverse(resources(region))),
+		RegionSort::Distance => listed.sort_by_key(|region| distances.get(&region.id).cloned().unwrap_or(usize::MAX))
+	}
+
+	listed.iter().map(|region| region.id).collect()
+}
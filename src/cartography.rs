@@ -0,0 +1,89 @@
+use tokens::*;
+use utility::*;
+
+pub fn survey(region: &Region, age: usize) -> Survey {
+    Survey {
+        region: region.id,
+        terrain: region.terrain.clone(),
+        particularities: region.particularities.storage().clone(),
+        age
+    }
+}
+
+// the surveyed regions: where the cartographer stands and everything around it
+fn surveyed(explorer: &Explorer, regions: &SelectionHashMap<Region>) -> Vec<RegionId> {
+    let mut surveyed = vec![];
+    if let Some(region) = explorer.region.and_then(|region| regions.storage().get(&region)) {
+        surveyed.push(region.id);
+        surveyed.extend(region.neighbors.iter());
+    }
+    surveyed
+}
+
+pub fn mappable(explorer: &Explorer) -> Result<(), String> {
+    if !explorer.has_trait(&ExplorerTrait::Cartographer) {
+        return Err(format!("only a {} can map.", ExplorerTrait::Cartographer));
+    }
+    if !explorer.has_equipment(&Equipment::Parchment(1)) {
+        return Err("mapping needs Parchment.".to_string());
+    }
+    Ok(())
+}
+
+pub fn preview(explorer: &Explorer, regions: &SelectionHashMap<Region>) -> String {
+    match mappable(explorer) {
+        Ok(()) => format!("a map of {} regions.", surveyed(explorer, regions).len()),
+        Err(reason) => reason
+    }
+}
+
+// consumes parchment, marks the regions as mapped and hands the explorer a map of them.
+pub fn map(explorer: &mut Explorer, regions: &mut SelectionHashMap<Region>, age: usize) -> Result<(), String> {
+    mappable(explorer)?;
+
+    let mut surveys = vec![];
+    for id in surveyed(explorer, regions) {
+        if let Some(region) = regions.storage_mut().get_mut(&id) {
+            let survey = survey(region, age);
+            region.mapped = Some(survey.clone());
+            surveys.push(survey);
+        }
+    }
+
+    explorer.take_equipment(&Equipment::Parchment(1));
+    explorer.inventory.insert(ExplorerItem::Map(surveys));
+
+    Ok(())
+}
+
+// the region as it was surveyed, without anything that needs an explorer on site
+pub fn charted(region: &Region, survey: &Survey) -> Region {
+    let mut region = region.clone();
+    region.particularities = SelectionStorage::new_from(&survey.particularities);
+    region.buildings = SelectionStorage::new();
+    region.resources = 0;
+    region.mapped = Some(survey.clone());
+    region
+}
+
+// newest survey of every region found on the maps the expedition carries
+pub fn carried_surveys(explorers: &SelectionStorage<Explorer>) -> Vec<Survey> {
+    let mut surveys: Vec<Survey> = vec![];
+    for explorer in explorers.iter() {
+        for item in explorer.inventory.iter() {
+            if let ExplorerItem::Map(map) = item {
+                for survey in map {
+                    match surveys.iter().position(|known| known.region == survey.region) {
+                        Some(index) => {
+                            if surveys[index].age < survey.age {
+                                surveys[index] = survey.clone();
+                            }
+                        },
+                        None => surveys.push(survey.clone())
+                    }
+                }
+            }
+        }
+    }
+    surveys
+}
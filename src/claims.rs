@@ -0,0 +1,136 @@
+use tokens::*;
+use utility::*;
+
+// the blockaded region an action is aimed at
+pub fn target(action: &ExplorerAction) -> Option<RegionId> {
+    match action {
+        ExplorerAction::Negotiate(region) | ExplorerAction::Dismantle(region) | ExplorerAction::Bypass(region) => Some(*region),
+        _ => None
+    }
+}
+
+// blockades around the explorer's region
+pub fn blockades(region: &Region, regions: &SelectionHashMap<Region>) -> Vec<RegionId> {
+    region.neighbors.iter()
+        .filter(|neighbor| regions.storage().get(neighbor).map(|neighbor| neighbor.blockade.is_some()).unwrap_or(false))
+        .cloned()
+        .collect()
+}
+
+pub fn claimed(regions: &SelectionHashMap<Region>) -> usize {
+    regions.iter().filter(|(_, region)| region.owner == Some(Faction::Expedition)).count()
+}
+
+// checks the explorer stands next to the blockade and hands it out
+fn blockade<'a>(explorer: &Explorer, target: &'a Region) -> Result<&'a Blockade, String> {
+    if !explorer.region.map(|region| target.neighbors.contains(&region)).unwrap_or(false) {
+        return Err(format!("region {} is not next to the explorer.", target.id));
+    }
+    match target.blockade {
+        Some(ref blockade) => Ok(blockade),
+        None => Err(format!("there is no blockade at region {}.", target.id))
+    }
+}
+
+pub fn negotiable(explorer: &Explorer, target: &Region) -> Result<(), String> {
+    let blockade = blockade(explorer, target)?;
+    if blockade.faction == Faction::Bandits && !explorer.has_trait(&ExplorerTrait::Ranger) {
+        return Err(format!("the {:?} only listen to a {}.", blockade.faction, ExplorerTrait::Ranger));
+    }
+    if !explorer.has_equipment(&blockade.toll) {
+        return Err(format!("the {:?} want {:?}.", blockade.faction, blockade.toll));
+    }
+    Ok(())
+}
+
+pub fn dismantlable(explorer: &Explorer, target: &Region) -> Result<(), String> {
+    blockade(explorer, target)?;
+    if !explorer.has_trait(&ExplorerTrait::Builder) {
+        return Err(format!("only an {} can dismantle a blockade.", ExplorerTrait::Builder));
+    }
+    if !explorer.has_equipment(&Equipment::Tools) {
+        return Err("dismantling needs Tools.".to_string());
+    }
+    Ok(())
+}
+
+// rangers slip through the woods, everyone else needs a rope to climb around
+pub fn bypassable(explorer: &Explorer, target: &Region) -> Result<(), String> {
+    blockade(explorer, target)?;
+    match target.terrain {
+        Terrain::Forest if explorer.has_trait(&ExplorerTrait::Ranger) => Ok(()),
+        Terrain::Mountain | Terrain::Coast if explorer.has_equipment(&Equipment::Rope) => Ok(()),
+        Terrain::Planes => Err("there is no cover to slip through unseen.".to_string()),
+        _ => Err(format!("the way around the blockade at {:?} is too rough.", target.terrain))
+    }
+}
+
+pub fn claimable(explorer: &Explorer, region: &Region) -> Result<(), String> {
+    if explorer.region != Some(region.id) {
+        return Err("the explorer is not in the region.".to_string());
+    }
+    match region.owner {
+        Some(Faction::Expedition) => Err("the region is already claimed.".to_string()),
+        // a camp makes a claim stick against those living there
+        Some(faction) if !region.has_building(Building::Camp) => Err(format!("the {:?} hold the region, a camp would make a claim stick.", faction)),
+        _ => Ok(())
+    }
+}
+
+pub fn preview(action: &ExplorerAction, explorer: &Explorer, regions: &SelectionHashMap<Region>) -> String {
+    let target = match target(action).and_then(|target| regions.storage().get(&target)) {
+        Some(target) => target,
+        None => return "the region is unknown.".to_string()
+    };
+    let feasible = match action {
+        ExplorerAction::Negotiate(_) => negotiable(explorer, target),
+        ExplorerAction::Dismantle(_) => dismantlable(explorer, target),
+        _ => bypassable(explorer, target)
+    };
+    match (feasible, action, &target.blockade) {
+        (Err(reason), _, _) => reason,
+        (Ok(()), ExplorerAction::Negotiate(_), _) => "the blockade is lifted.".to_string(),
+        (Ok(()), ExplorerAction::Dismantle(_), Some(blockade)) if blockade.strength > 1 => format!("{} more turns of work.", blockade.strength - 1),
+        (Ok(()), ExplorerAction::Dismantle(_), _) => "the blockade is torn down.".to_string(),
+        (Ok(()), _, _) => format!("the explorer slips into region {}.", target.id)
+    }
+}
+
+// resolves an action against a blockade next to the explorer, bypassing moves the explorer.
+pub fn resolve(action: &ExplorerAction, explorer: &mut Explorer, regions: &mut SelectionHashMap<Region>, age: usize) -> Result<(), String> {
+    let target = match target(action).and_then(|target| regions.storage_mut().get_mut(&target)) {
+        Some(target) => target,
+        None => return Err("the region is unknown.".to_string())
+    };
+
+    match action {
+        ExplorerAction::Negotiate(_) => {
+            negotiable(explorer, target)?;
+            if let Some(blockade) = target.blockade.take() {
+                explorer.take_equipment(&blockade.toll);
+            }
+        },
+        ExplorerAction::Dismantle(_) => {
+            dismantlable(explorer, target)?;
+            let torn_down = match target.blockade {
+                Some(ref mut blockade) => {
+                    blockade.strength = blockade.strength.saturating_sub(1);
+                    blockade.strength == 0
+                },
+                None => false
+            };
+            if torn_down {
+                target.blockade = None;
+            }
+        },
+        _ => {
+            bypassable(explorer, target)?;
+            explorer.note_action(action);
+            explorer.track(target.id, age);
+            return Ok(());
+        }
+    }
+
+    explorer.note_action(action);
+    Ok(())
+}
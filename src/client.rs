@@ -1,15 +1,11 @@
 
 use std::fs::File;
-use std::net::{TcpStream, Shutdown};
 use std::sync::mpsc::Receiver;
 use std::io::prelude::*;
 use std::io;
 
-use bincode::{serialize, deserialize};
-
 use tui::Terminal;
 use tui::backend::RawBackend;
-use termion::event;
 
 use uuid::Uuid;
 
@@ -18,6 +14,17 @@ use tokens::*;
 use utility::*;
 
 use client_dashboard::draw;
+use crafting;
+use actions;
+use tracking;
+use dialog;
+use investigation;
+use delivery;
+use crafting::Recipe;
+use keymap::{Keymap, Command};
+use journal::Journal;
+use client_api::RealmsClient;
+use browser::{self, RegionSort, RegionFilter};
 
 #[derive(Debug)]
 pub enum InteractiveUi {
@@ -27,77 +34,146 @@ pub enum InteractiveUi {
 	Regions,
 	ExplorerMove,
 	ExplorerActions,
+	ExplorerBuild,
 	ExplorerInventory,
-	Particularities
+	ExplorerCraft,
+	ExplorerGive,
+	ExplorerFollow,
+	ExplorerTalk,
+	ExplorerReport,
+	ExplorerDeliveries,
+	Dialog,
+	Particularities,
+	Journal
 }
 
 #[derive(Debug)]
 pub struct Data {
 	pub id: ClientId,
 	pub realm: Realm,
-	pub realms: SelectionStorage<RealmId>,
+	pub realms: SelectionStorage<RealmSummary>,
+	// a realm waiting for the delete command a second time
+	pub deleting: Option<RealmId>,
 	pub explorer_orders: SelectionStorage<ExplorerOrders>,
+	pub recipes: SelectionStorage<Recipe>,
+	pub actions: SelectionStorage<ExplorerAction>,
+	// the build menu, opened from the actions
+	pub buildings: SelectionStorage<Building>,
+	pub recipients: SelectionStorage<ExplorerId>,
+	pub tracked: SelectionStorage<ExplorerId>,
+	pub characters: SelectionStorage<CharacterId>,
+	pub choices: SelectionStorage<usize>,
+	pub reports: SelectionStorage<(CharacterId, Finding)>,
+	pub deliveries: SelectionStorage<Delivery>,
 	pub active: InteractiveUi,
-	pub tabs: SelectionStorage<String>
+	pub tabs: SelectionStorage<String>,
+	pub keymap: Keymap,
+	pub help: bool,
+	pub journal: Journal,
+	// the regions tab, listed by id
+	pub browsed: SelectionStorage<RegionId>,
+	pub region_sort: RegionSort,
+	pub region_filter: RegionFilter
 }
 
 #[derive(Debug, Clone)]
 pub enum ExplorerOrders {
 	Inventory,
 	Actions,
+	Craft,
+	Follow,
+	Talk,
+	Report,
+	Deliveries,
 	Embark,
 	Move
 }
 
+// where the terminal client keeps its id between games
+const CLIENT_ID: &str = "client.id";
+const OFFLINE_ID: &str = "offline.id";
+
 pub struct Periscope {
-	pub stream: TcpStream,
+	pub client: RealmsClient,
 	pub data: Data
 }
 
 impl Periscope {
-	pub fn new(mut stream: TcpStream) -> Periscope {
-
-		let mut client_id: Uuid = Uuid::new_v4();
-    	if let Ok(mut file) = File::open("client.id") {
+	// offline play keeps an id file of its own, the server would not know the id it is given
+	pub fn new(mut client: RealmsClient, keymap: Keymap, offline: bool) -> Periscope {
+		let id_file = if offline { OFFLINE_ID } else { CLIENT_ID };
+		let stored_client_id = File::open(id_file).ok().map(|mut file| {
 			let mut stored_client_id = String::new();
-		    file.read_to_string(&mut stored_client_id).expect("could not read contents of file client.id");
-		    client_id = Uuid::parse_str(&stored_client_id).expect("could not parse stored client id as usize.");
+			file.read_to_string(&mut stored_client_id).unwrap_or_else(|_| panic!("could not read contents of file {}", id_file));
+			Uuid::parse_str(&stored_client_id).unwrap_or_else(|_| panic!("could not parse stored client id in {}", id_file))
+		});
 
-		    // try to connect previous client
-		    if let RealmsProtocol::Connect(id) = send_request(&mut stream, client_id, RealmsProtocol::Connect(client_id)) {
-				client_id = id;
-			}
-    	} else {
-    		// register new client
-			if let RealmsProtocol::Connect(id) = send_request(&mut stream, client_id, RealmsProtocol::Register) {
-				client_id = id;
+		let mut client_id: Uuid = Uuid::new_v4();
+		match stored_client_id {
+			// the realms of the last offline game are gone with its universe, and so are their journals
+			Some(stored_client_id) if offline => {
+				Journal::clear(stored_client_id);
+				if let Ok(id) = client.register() {
+					client_id = id;
+				}
+			},
+			// try to connect previous client
+			Some(stored_client_id) => {
+				client_id = stored_client_id;
+				if let Ok(id) = client.connect(client_id) {
+					client_id = id;
+				}
+			},
+			// register new client
+			None => {
+				if let Ok(id) = client.register() {
+					client_id = id;
+				}
 			}
-    	}
+		}
 
-		File::create("client.id").expect("could not create file client.id").write_fmt(format_args!("{}", client_id)).expect("could not write to file client.id");
+		File::create(id_file).unwrap_or_else(|_| panic!("could not create file {}", id_file)).write_fmt(format_args!("{}", client_id)).unwrap_or_else(|_| panic!("could not write to file {}", id_file));
 
 		// init realm, should get overriden by the server
 		let mut realm = Realm::new(0);
-		if let RealmsProtocol::Realm(response_realm) = send_request(&mut stream, client_id, RealmsProtocol::RequestNewRealm) {
+		if let Ok(response_realm) = client.new_realm() {
 			realm = response_realm;
 		}
 		
 		let mut realms = SelectionStorage::new();
-		if let RealmsProtocol::RealmsList(response_realms) = send_request(&mut stream, client_id, RealmsProtocol::RequestRealmsList) {
+		if let Ok(response_realms) = client.realms() {
 			realms = response_realms;
 		}
 
 		realms.last();
 
+		let journal = Journal::load(client_id, &realm);
+
 		let mut periscope = Periscope {
-			stream,
+			client,
 			data: Data {
 				id: client_id,
 				realm,
 				realms,
+				deleting: None,
 				explorer_orders: SelectionStorage::new(),
+				recipes: SelectionStorage::new(),
+				actions: SelectionStorage::new(),
+				buildings: SelectionStorage::new(),
+				recipients: SelectionStorage::new(),
+				tracked: SelectionStorage::new(),
+				characters: SelectionStorage::new(),
+				choices: SelectionStorage::new(),
+				reports: SelectionStorage::new(),
+				deliveries: SelectionStorage::new(),
 				active: InteractiveUi::Explorers,
-				tabs: SelectionStorage::new_from(&vec!["Current Realm".to_string(), "Realms".to_string()])
+				tabs: SelectionStorage::new_from(&vec!["Current Realm".to_string(), "Realms".to_string(), "Journal".to_string(), "Regions".to_string()]),
+				keymap,
+				help: false,
+				journal,
+				browsed: SelectionStorage::new(),
+				region_sort: RegionSort::Id,
+				region_filter: RegionFilter::All
 			}
 		};
 
@@ -116,7 +192,7 @@ impl Periscope {
 	    
 		loop {
 
-			if !handle_events(rx, &mut self.stream, &mut self.data) {
+			if !handle_events(rx, &mut self.client, &mut self.data) {
 				break;
 			}
 
@@ -130,22 +206,14 @@ impl Periscope {
 	}
 }
 
-fn send_request(stream: &mut TcpStream, client: ClientId, request: RealmsProtocol) -> RealmsProtocol {
-	let raw = serialize(&(client, request)).expect("could not serialize data package for request.");
-	stream.write(&raw).expect("could not write request to tcp stream.");
-	stream.flush().expect("could not flush request stream.");
-	handle_response(stream)
+// sends an order and writes it down in the journal along with the answer
+fn send_order(client: &mut RealmsClient, journal: &mut Journal, request: RealmsProtocol) -> Result<Realm, String> {
+	let outcome = client.order(request.clone());
+	journal.order(&request, &outcome);
+	outcome
 }
 
-fn handle_response(stream: &mut TcpStream) -> RealmsProtocol {
-	let mut buffer = [0; 4096];
-    stream.read(&mut buffer).expect("could not read response into buffer.");
-    stream.flush().expect("could not flush response stream.");
-    let response: RealmsProtocol = deserialize(&buffer).expect("could not deserialize server response");
-    response
-}
-
-fn handle_events(rx: &Receiver<Event>, stream: &mut TcpStream, data: &mut Data) -> bool {
+fn handle_events(rx: &Receiver<Event>, client: &mut RealmsClient, data: &mut Data) -> bool {
 	let mut should_continue = true;
 
 	let evt = rx.recv().expect("could not receive input event.");
@@ -154,55 +222,106 @@ fn handle_events(rx: &Receiver<Event>, stream: &mut TcpStream, data: &mut Data)
 			// todo: pulling updates (also keep-alive)
 	    },
 	    Event::Input(key) => {
-	    	match key {
-	    	    event::Key::Char('\t') => {
-	    	    	data.tabs.next();
+	    	let before = data.realm.clone();
+
+	    	// only commands the focused part of the ui lists get through
+	    	let command = data.keymap.command(key).filter(|command| commands(&data.active).iter().any(|(listed, _)| listed == command));
+	    	match command {
+	    	    Some(Command::Help) => {
+	    	    	data.help = !data.help;
+	    	    },
+	    	    Some(Command::Quit) => {
+					// keeps running if the server did not let the client go
+					should_continue = client.quit().is_err();
+		    	},
+		    	// any other key closes the help
+		    	Some(_) if data.help => {
+		    		data.help = false;
+		    	},
+	    	    Some(Command::NextTab) => {
+	    	    	data.tabs.select_next();
 	    	    	match data.tabs.current_index() {
 		                0 => {
 		                    data.active = InteractiveUi::Explorers;
 		                },
 		                1 => {
 		                    data.active = InteractiveUi::Realms;
+		                    // ages and progress may have moved on since the list was fetched
+		                    let selected = data.realms.current().map(|summary| summary.id).unwrap_or(data.realm.id);
+		                    update_realms(client, data, selected);
+		                },
+		                2 => {
+		                    data.active = InteractiveUi::Journal;
 		                },
 		                _ => {
 		                    data.active = InteractiveUi::Regions;
+		                    update_browsed(data);
 		                }
 		            }
 	    	    },
-	    	    event::Key::Char('q') => {
-					if let RealmsProtocol::Quit = send_request(stream, data.id, RealmsProtocol::Quit) {
-						stream.shutdown(Shutdown::Both).expect("connection should have terminated.");
-						should_continue = false;
-					}
-		    	},
-		    	_ => {
+		    	Some(command) => {
 					match data.active {
 					    InteractiveUi::Explorers => {
-					    	handle_explorer_events(stream, data, key);
+					    	handle_explorer_events(client, data, command);
 					    },
 					    InteractiveUi::ExplorerOrders => {
-					    	handle_explorer_orders_events(stream, data, key);
+					    	handle_explorer_orders_events(client, data, command);
 					    },
 					    InteractiveUi::Realms => {
-					    	handle_realms_events(stream, data, key);
+					    	handle_realms_events(client, data, command);
 					    },
 					    InteractiveUi::ExplorerMove => {
-					    	handle_explorer_move_events(stream, data, key);
+					    	handle_explorer_move_events(client, data, command);
 					    },
 					    InteractiveUi::ExplorerActions => {
-					    	handle_explorer_actions_events(stream, data, key);
+					    	handle_explorer_actions_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerBuild => {
+					    	handle_explorer_build_events(client, data, command);
 					    },
 					    InteractiveUi::ExplorerInventory => {
-					    	handle_explorer_inventory_events(stream, data, key);
+					    	handle_explorer_inventory_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerCraft => {
+					    	handle_explorer_craft_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerGive => {
+					    	handle_explorer_give_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerFollow => {
+					    	handle_explorer_follow_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerTalk => {
+					    	handle_explorer_talk_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerReport => {
+					    	handle_explorer_report_events(client, data, command);
+					    },
+					    InteractiveUi::ExplorerDeliveries => {
+					    	handle_explorer_deliveries_events(client, data, command);
+					    },
+					    InteractiveUi::Dialog => {
+					    	handle_dialog_events(client, data, command);
 					    },
 					    InteractiveUi::Particularities => {
-					    	handle_particularities_events(stream, data, key);
+					    	handle_particularities_events(client, data, command);
 					    },
 					    InteractiveUi::Regions => {
-					    	handle_regions_events(stream, data, key);
+					    	handle_regions_events(client, data, command);
+					    },
+					    InteractiveUi::Journal => {
+					    	handle_journal_events(client, data, command);
 					    }
 					}
-		    	}
+		    	},
+		    	None => { }
+	    	}
+
+	    	// each realm has its own journal
+	    	if data.realm.id == data.journal.realm {
+	    		data.journal.changes(&before, &data.realm);
+	    	} else {
+	    		data.journal = Journal::load(data.id, &data.realm);
 	    	}
 		}
 	}
@@ -210,91 +329,278 @@ fn handle_events(rx: &Receiver<Event>, stream: &mut TcpStream, data: &mut Data)
 	should_continue
 }
 
-fn handle_realms_events(stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
-	    	data.realms.prev();
+// commands each part of the ui reacts to, with what they do there
+pub fn commands(active: &InteractiveUi) -> Vec<(Command, &'static str)> {
+	let mut commands = match active {
+		InteractiveUi::Explorers => vec![
+			(Command::Up, "previous explorer"),
+			(Command::Down, "next explorer"),
+			(Command::Select, "orders"),
+			(Command::Right, "orders"),
+			(Command::Left, "back to the first region"),
+			(Command::Actions, "actions"),
+			(Command::Inventory, "inventory"),
+			(Command::Move, "move"),
+			(Command::Craft, "craft")
+		],
+		InteractiveUi::ExplorerOrders => vec![
+			(Command::Up, "previous order"),
+			(Command::Down, "next order"),
+			(Command::Select, "give order"),
+			(Command::Right, "particularities"),
+			(Command::Left, "back to the explorers"),
+			(Command::Back, "back to the explorers")
+		],
+		InteractiveUi::Realms => vec![
+			(Command::Up, "previous realm"),
+			(Command::Down, "next realm"),
+			(Command::Select, "resume realm"),
+			(Command::NewRealm, "new realm"),
+			(Command::ArchiveRealm, "archive/restore realm"),
+			(Command::DeleteRealm, "delete realm"),
+			(Command::DuplicateRealm, "duplicate realm")
+		],
+		InteractiveUi::Regions => vec![
+			(Command::Up, "previous region"),
+			(Command::Down, "next region"),
+			(Command::Left, "distances from the previous explorer"),
+			(Command::Right, "distances from the next explorer"),
+			(Command::Sort, "sort"),
+			(Command::Filter, "filter")
+		],
+		InteractiveUi::Journal => vec![
+			(Command::Up, "earlier entry"),
+			(Command::Down, "later entry")
+		],
+		InteractiveUi::ExplorerInventory => vec![
+			(Command::Up, "previous item"),
+			(Command::Down, "next item"),
+			(Command::Select, "drop/forget"),
+			(Command::Give, "give"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerGive => vec![
+			(Command::Up, "previous explorer"),
+			(Command::Down, "next explorer"),
+			(Command::Select, "give"),
+			(Command::Back, "back to the inventory")
+		],
+		InteractiveUi::Dialog => vec![
+			(Command::Up, "previous answer"),
+			(Command::Down, "next answer"),
+			(Command::Select, "answer"),
+			(Command::Back, "leave the conversation")
+		],
+		InteractiveUi::Particularities => vec![
+			(Command::Up, "previous particularity"),
+			(Command::Down, "next particularity"),
+			(Command::Select, "pick/investigate"),
+			(Command::Left, "back to the orders")
+		],
+		InteractiveUi::ExplorerMove => vec![
+			(Command::Up, "previous region"),
+			(Command::Down, "next region"),
+			(Command::Select, "move there"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerActions => vec![
+			(Command::Up, "previous action"),
+			(Command::Down, "next action"),
+			(Command::Select, "act/build"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerBuild => vec![
+			(Command::Up, "previous building"),
+			(Command::Down, "next building"),
+			(Command::Select, "build"),
+			(Command::Back, "back to the actions")
+		],
+		InteractiveUi::ExplorerCraft => vec![
+			(Command::Up, "previous recipe"),
+			(Command::Down, "next recipe"),
+			(Command::Select, "craft"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerFollow => vec![
+			(Command::Up, "previous explorer"),
+			(Command::Down, "next explorer"),
+			(Command::Select, "follow"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerTalk => vec![
+			(Command::Up, "previous character"),
+			(Command::Down, "next character"),
+			(Command::Select, "talk"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerReport => vec![
+			(Command::Up, "previous finding"),
+			(Command::Down, "next finding"),
+			(Command::Select, "report"),
+			(Command::Back, "back to the orders")
+		],
+		InteractiveUi::ExplorerDeliveries => vec![
+			(Command::Up, "previous delivery"),
+			(Command::Down, "next delivery"),
+			(Command::Select, "deliver"),
+			(Command::Back, "back to the orders")
+		]
+	};
+	commands.extend(vec![(Command::NextTab, "next tab"), (Command::Help, "help"), (Command::Quit, "quit")]);
+	commands
+}
+
+fn handle_realms_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+	    	data.realms.select_prev();
 		},
-		event::Key::Down => {
-	    	data.realms.next();
+		Command::Down => {
+	    	data.realms.select_next();
 		},
-		event::Key::Char('r') => {
-			if let RealmsProtocol::Realm(response_realm) = send_request(stream, data.id, RealmsProtocol::RequestNewRealm) {
+		Command::NewRealm => {
+			if let Ok(response_realm) = client.new_realm() {
 				data.realm = response_realm;
 			}
-			if let RealmsProtocol::RealmsList(response_realms) = send_request(stream, data.id, RealmsProtocol::RequestRealmsList) {
-				data.realms = response_realms;
-			}
-			data.realms.last();
+			let realm_id = data.realm.id;
+			update_realms(client, data, realm_id);
 		},
-		event::Key::Char('\n') => {
-    		{
-    			let realm_id = data.realms.current().expect("could not access current realm selection.");
-	    		let current_realm_id = data.realm.id;
-	    		if *realm_id != current_realm_id || data.realm.island.regions.iter().len() == 0 {
-		    		if let RealmsProtocol::Realm(response_realm) = send_request(stream, data.id, RealmsProtocol::RequestRealm(*realm_id)) {
+		Command::Select => {
+			if let Some(realm_id) = data.realms.current().map(|summary| summary.id) {
+	    		if realm_id != data.realm.id || data.realm.island.regions.iter().len() == 0 {
+		    		if let Ok(response_realm) = client.realm(realm_id) {
 						data.realm = response_realm;
 					}
 	    		}
 		    	data.active = InteractiveUi::Explorers;
-		    	data.tabs.next();
-    		}
+		    	data.tabs.at(0);
+			}
+		},
+		Command::ArchiveRealm => {
+			if let Some(realm_id) = data.realms.current().map(|summary| summary.id) {
+				if let Ok(response_realms) = client.archive_realm(realm_id) {
+					data.realms = response_realms;
+				}
+				select_realm(data, realm_id);
+			}
+		},
+		Command::DuplicateRealm => {
+			if let Some(realm_id) = data.realms.current().map(|summary| summary.id) {
+				if let Ok(response_realms) = client.duplicate_realm(realm_id) {
+					data.realms = response_realms;
+				}
+				// the duplicate has the highest id
+				let duplicate = data.realms.iter().map(|summary| summary.id).max().unwrap_or(realm_id);
+				select_realm(data, duplicate);
+			}
+		},
+		Command::DeleteRealm => {
+			match (data.realms.current().map(|summary| summary.id), data.deleting) {
+				(Some(realm_id), Some(deleting)) if realm_id == deleting => {
+					let index = data.realms.current_index();
+					if let Ok(response_realms) = client.delete_realm(realm_id) {
+						data.realms = response_realms;
+						Journal::delete(data.id, realm_id);
+					}
+					data.realms.at(index.min(data.realms.iter().len().saturating_sub(1)));
+
+					// the open realm is gone, another one takes its place
+					if data.realm.id == realm_id {
+						let response = match data.realms.current().map(|summary| summary.id) {
+							Some(open) => client.realm(open),
+							None => client.new_realm()
+						};
+						if let Ok(response_realm) = response {
+							data.realm = response_realm;
+						}
+						data.journal = Journal::load(data.id, &data.realm);
+						let open = data.realm.id;
+						update_realms(client, data, open);
+					}
+				},
+				(realm_id, _) => {
+					data.deleting = realm_id;
+					return;
+				}
+			}
 		},
 		_ => { }
 	}
+	data.deleting = None;
+}
+
+fn update_realms(client: &mut RealmsClient, data: &mut Data, selected: RealmId) {
+	if let Ok(response_realms) = client.realms() {
+		data.realms = response_realms;
+	}
+	select_realm(data, selected);
 }
 
-fn handle_explorer_events(_stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
-	    	data.realm.expedition.explorers.prev();
+fn select_realm(data: &mut Data, realm_id: RealmId) {
+	if let Some(index) = data.realms.iter().position(|summary| summary.id == realm_id) {
+		data.realms.at(index);
+	}
+}
+
+fn handle_explorer_events(_client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+	    	data.realm.expedition.explorers.select_prev();
 			update_explorer_available_orders(data);
 		},
-		event::Key::Down => {
-	    	data.realm.expedition.explorers.next();
+		Command::Down => {
+	    	data.realm.expedition.explorers.select_next();
 	    	update_explorer_available_orders(data);
 		},
-		event::Key::Right | event::Key::Char('\n') => {
+		Command::Right | Command::Select => {
 	    	data.active = InteractiveUi::ExplorerOrders;
 		},
-		event::Key::Left => {
+		Command::Left => {
 	    	data.active = InteractiveUi::Explorers;
 	    	data.realm.island.regions.at(0);
 		},
-		event::Key::Char('a') => {
+		Command::Actions => {
 			data.active = InteractiveUi::ExplorerActions;
 		},
-		event::Key::Char('i') => {
+		Command::Inventory => {
 			data.active = InteractiveUi::ExplorerInventory;
 		},
-		event::Key::Char('m') => {
+		Command::Move => {
 			data.active = InteractiveUi::ExplorerMove;
 		},
+		Command::Craft => {
+			data.active = InteractiveUi::ExplorerCraft;
+		},
 		_ => { }
 	}
 }
 
-fn handle_explorer_orders_events(_stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
-	    	data.explorer_orders.prev();
+fn handle_explorer_orders_events(_client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+	    	data.explorer_orders.select_prev();
 		},
-		event::Key::Down => {
-	    	data.explorer_orders.next();
+		Command::Down => {
+	    	data.explorer_orders.select_next();
 		},
-		event::Key::Left | event::Key::Backspace => {
+		Command::Left | Command::Back => {
 	    	data.active = InteractiveUi::Explorers;
 			update_explorer_available_orders(data);
 		},
-		event::Key::Right => {
+		Command::Right => {
 	    	data.active = InteractiveUi::Particularities;
 		},
-		event::Key::Char('\n') => {
+		Command::Select => {
 			match data.explorer_orders.current() {
 			    Some(ExplorerOrders::Inventory) => {
 		        	data.active = InteractiveUi::ExplorerInventory;
 			    },
 			    Some(ExplorerOrders::Actions) => data.active = InteractiveUi::ExplorerActions,
+			    Some(ExplorerOrders::Craft) => data.active = InteractiveUi::ExplorerCraft,
+			    Some(ExplorerOrders::Follow) => data.active = InteractiveUi::ExplorerFollow,
+			    Some(ExplorerOrders::Talk) => data.active = InteractiveUi::ExplorerTalk,
+			    Some(ExplorerOrders::Report) => data.active = InteractiveUi::ExplorerReport,
+			    Some(ExplorerOrders::Deliveries) => data.active = InteractiveUi::ExplorerDeliveries,
 			    Some(ExplorerOrders::Move) | Some(ExplorerOrders::Embark) => data.active = InteractiveUi::ExplorerMove,
 			    None => {
 			    	data.active = InteractiveUi::Explorers;
@@ -306,24 +612,24 @@ fn handle_explorer_orders_events(_stream: &mut TcpStream, data: &mut Data, key:
 	}
 }
 
-fn handle_explorer_move_events(stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
-	    	data.realm.island.regions.prev();
+fn handle_explorer_move_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+	    	data.realm.island.regions.select_prev();
 		},
-		event::Key::Down => {
-	    	data.realm.island.regions.next();
+		Command::Down => {
+	    	data.realm.island.regions.select_next();
 		},
-		event::Key::Backspace => {
+		Command::Back => {
 	    	data.active = InteractiveUi::ExplorerOrders;
 			update_explorer_available_orders(data);
 		},
-		event::Key::Char('\n') => {
+		Command::Select => {
     		{
 				// request reset explorers index, we set it back afterwards
 				let last_explorers_index = data.realm.expedition.explorers.current_index();
 	    		
-	    		if let RealmsProtocol::Realm(response_realm) = explorer_move(stream, data.id, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
+	    		if let Ok(response_realm) = explorer_move(client, &mut data.journal, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
 		    		data.realm = response_realm;
 				}
 				data.realm.expedition.explorers.at(last_explorers_index);
@@ -336,53 +642,168 @@ fn handle_explorer_move_events(stream: &mut TcpStream, data: &mut Data, key: eve
 	}
 }
 
-fn handle_explorer_actions_events(stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
+fn handle_explorer_actions_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.actions.select_prev();
 		},
-		event::Key::Down => {
+		Command::Down => {
+			data.actions.select_next();
 		},
-		event::Key::Backspace => {
+		Command::Back => {
 	    	data.active = InteractiveUi::ExplorerOrders;
 			update_explorer_available_orders(data);
 		},
-		event::Key::Char('\n') => {
-    		{
+		Command::Select => {
+			if let Some(ExplorerAction::Build(_)) = data.actions.current() {
+				data.active = InteractiveUi::ExplorerBuild;
+			} else {
 				// request reset explorers index, we set it back afterwards
 				let last_explorers_index = data.realm.expedition.explorers.current_index();
+				let last_actions_index = data.actions.current_index();
 
-	    		if let RealmsProtocol::Realm(response_realm) = explorer_action(stream, data.id, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
+	    		if let Ok(response_realm) = explorer_action(client, &mut data.journal, data.realm.id, &data.actions, &mut data.realm.expedition.explorers) {
 		    		data.realm = response_realm;
 				}
 				data.realm.expedition.explorers.at(last_explorers_index);
+				update_explorer_available_orders(data);
+				data.actions.at(last_actions_index);
     		}
-        	sync_regions_with_explorer(data);
 		},
 		_ => { }
 	}
 }
 
-fn handle_explorer_inventory_events(stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
+fn handle_explorer_build_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.buildings.select_prev();
+		},
+		Command::Down => {
+			data.buildings.select_next();
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerActions;
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			let last_actions_index = data.actions.current_index();
+			let last_buildings_index = data.buildings.current_index();
+
+			if let Ok(response_realm) = explorer_build(client, &mut data.journal, data.realm.id, &data.buildings, &mut data.realm.expedition.explorers) {
+				data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+			data.actions.at(last_actions_index);
+			data.buildings.at(last_buildings_index);
+		},
+		_ => { }
+	}
+}
+
+fn handle_explorer_inventory_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
 			if let Some(ref mut explorer) = data.realm.expedition.explorers.current_mut() {
-			    explorer.inventory.prev();
+			    explorer.inventory.select_prev();
 			}
 		},
-		event::Key::Down => {
+		Command::Down => {
 			if let Some(ref mut explorer) = data.realm.expedition.explorers.current_mut() {
-			    explorer.inventory.next();
+			    explorer.inventory.select_next();
 			}
 		},
-		event::Key::Char('\n') => {
+		Command::Select => {
 			let last_explorers_index = data.realm.expedition.explorers.current_index();
-    		if let RealmsProtocol::Realm(response_realm) = explorer_drop(stream, data.id, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
+    		if let Ok(response_realm) = explorer_drop(client, &mut data.journal, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
 	    		data.realm = response_realm;
 			}
 			data.realm.expedition.explorers.at(last_explorers_index);
     		sync_regions_with_explorer(data);
 		},
-		event::Key::Backspace => {
+		Command::Give if data.recipients.current().is_some() => {
+			data.active = InteractiveUi::ExplorerGive;
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerOrders;
+			update_explorer_available_orders(data);
+		},
+		_ => { }
+	}
+}
+
+fn handle_explorer_give_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.recipients.select_prev();
+		},
+		Command::Down => {
+			data.recipients.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			if let Ok(response_realm) = explorer_give(client, &mut data.journal, data.realm.id, &data.recipients, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+	    	data.active = InteractiveUi::ExplorerInventory;
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerInventory;
+		},
+		_ => { }
+	}
+}
+
+fn handle_explorer_follow_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.tracked.select_prev();
+		},
+		Command::Down => {
+			data.tracked.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			let last_tracked_index = data.tracked.current_index();
+			if let Ok(response_realm) = explorer_follow(client, &mut data.journal, data.realm.id, &data.tracked, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+			data.tracked.at(last_tracked_index);
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerOrders;
+			update_explorer_available_orders(data);
+		},
+		_ => { }
+	}
+}
+
+fn handle_explorer_talk_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.characters.select_prev();
+		},
+		Command::Down => {
+			data.characters.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			let last_characters_index = data.characters.current_index();
+			if let Ok(response_realm) = explorer_talk(client, &mut data.journal, data.realm.id, &data.characters, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+				data.realm.expedition.explorers.at(last_explorers_index);
+				update_explorer_available_orders(data);
+				data.characters.at(last_characters_index);
+				update_dialog_choices(data);
+				data.active = InteractiveUi::Dialog;
+			}
+		},
+		Command::Back => {
 	    	data.active = InteractiveUi::ExplorerOrders;
 			update_explorer_available_orders(data);
 		},
@@ -390,25 +811,135 @@ fn handle_explorer_inventory_events(stream: &mut TcpStream, data: &mut Data, key
 	}
 }
 
-fn handle_particularities_events(stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
+fn handle_explorer_report_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.reports.select_prev();
+		},
+		Command::Down => {
+			data.reports.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			if let Ok(response_realm) = explorer_report(client, &mut data.journal, data.realm.id, &data.reports, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+			if data.reports.current().is_none() {
+		    	data.active = InteractiveUi::ExplorerOrders;
+			}
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerOrders;
+			update_explorer_available_orders(data);
+		},
+		_ => { }
+	}
+}
+
+fn handle_explorer_deliveries_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.deliveries.select_prev();
+		},
+		Command::Down => {
+			data.deliveries.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			if let Ok(response_realm) = explorer_deliver(client, &mut data.journal, data.realm.id, &data.deliveries, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+			if data.deliveries.current().is_none() {
+		    	data.active = InteractiveUi::ExplorerOrders;
+			}
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerOrders;
+			update_explorer_available_orders(data);
+		},
+		_ => { }
+	}
+}
+
+fn handle_dialog_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.choices.select_prev();
+		},
+		Command::Down => {
+			data.choices.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			let last_characters_index = data.characters.current_index();
+			if let Ok(response_realm) = explorer_answer(client, &mut data.journal, data.realm.id, &data.characters, &data.choices, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+			data.characters.at(last_characters_index);
+			update_dialog_choices(data);
+
+			// the conversation is over once there is nothing left to answer
+			if data.choices.current().is_none() {
+				data.active = InteractiveUi::ExplorerTalk;
+			}
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerTalk;
+		},
+		_ => { }
+	}
+}
+
+fn handle_explorer_craft_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+			data.recipes.select_prev();
+		},
+		Command::Down => {
+			data.recipes.select_next();
+		},
+		Command::Select => {
+			let last_explorers_index = data.realm.expedition.explorers.current_index();
+			if let Ok(response_realm) = explorer_craft(client, &mut data.journal, data.realm.id, &data.recipes, &mut data.realm.expedition.explorers) {
+	    		data.realm = response_realm;
+			}
+			data.realm.expedition.explorers.at(last_explorers_index);
+			update_explorer_available_orders(data);
+			data.active = InteractiveUi::ExplorerCraft;
+		},
+		Command::Back => {
+	    	data.active = InteractiveUi::ExplorerOrders;
+			update_explorer_available_orders(data);
+		},
+		_ => { }
+	}
+}
+
+fn handle_particularities_events(client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
 			if let Some(region) = data.realm.island.regions.current_mut() {
-			    region.particularities.prev();
+			    region.particularities.select_prev();
 			}
 		},
-		event::Key::Down => {
+		Command::Down => {
 			if let Some(region) = data.realm.island.regions.current_mut() {
-			    region.particularities.next();
+			    region.particularities.select_next();
 			}
 		},
-		event::Key::Left => {
+		Command::Left => {
 	    	data.active = InteractiveUi::ExplorerOrders;
 			update_explorer_available_orders(data);
 		},
-		event::Key::Char('\n') => {
+		Command::Select => {
 			let last_explorers_index = data.realm.expedition.explorers.current_index();
-			if let RealmsProtocol::Realm(response_realm) = explorer_handle_particularity(stream, data.id, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
+			if let Ok(response_realm) = explorer_handle_particularity(client, &mut data.journal, data.realm.id, &mut data.realm.island.regions, &mut data.realm.expedition.explorers) {
 	    		data.realm = response_realm;
 			}
 			data.realm.expedition.explorers.at(last_explorers_index);
@@ -418,13 +949,63 @@ fn handle_particularities_events(stream: &mut TcpStream, data: &mut Data, key: e
 	}
 }
 
-fn handle_regions_events(_stream: &mut TcpStream, data: &mut Data, key: event::Key) {
-	match key {
-		event::Key::Up => {
-	    	data.realm.island.regions.prev();
+fn handle_regions_events(_client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+	    	data.browsed.select_prev();
 		},
-		event::Key::Down => {
-	    	data.realm.island.regions.next();
+		Command::Down => {
+	    	data.browsed.select_next();
+		},
+		Command::Left => {
+	    	data.realm.expedition.explorers.select_prev();
+	    	update_browsed(data);
+	    	update_explorer_available_orders(data);
+		},
+		Command::Right => {
+	    	data.realm.expedition.explorers.select_next();
+	    	update_browsed(data);
+	    	update_explorer_available_orders(data);
+		},
+		Command::Sort => {
+	    	data.region_sort = data.region_sort.next();
+	    	update_browsed(data);
+		},
+		Command::Filter => {
+	    	data.region_filter = data.region_filter.next();
+	    	update_browsed(data);
+		},
+		_ => { }
+	}
+
+	// updating the orders selects the explorer's region, the listed one is kept
+	if let Some(region) = data.browsed.current() {
+		data.realm.island.regions.at(*region);
+	}
+}
+
+// lists the regions anew, staying on the selected region if it is still listed
+fn update_browsed(data: &mut Data) {
+	let selected = data.realm.island.regions.current().map(|region| region.id);
+	let distances = match data.realm.expedition.explorers.current().and_then(|explorer| explorer.region) {
+		Some(region) => browser::distances(&data.realm.island.regions, region),
+		None => Default::default()
+	};
+	let browsed = browser::browse(&data.realm.island.regions, &data.realm.expedition.explorers, &distances, data.region_sort, data.region_filter);
+
+	data.browsed = SelectionStorage::new_from(&browsed);
+	if let Some(index) = browsed.iter().position(|region| Some(*region) == selected) {
+		data.browsed.at(index);
+	}
+}
+
+fn handle_journal_events(_client: &mut RealmsClient, data: &mut Data, command: Command) {
+	match command {
+		Command::Up => {
+	    	data.journal.entries.select_prev();
+		},
+		Command::Down => {
+	    	data.journal.entries.select_next();
 		},
 		_ => { }
 	}
@@ -441,21 +1022,184 @@ fn update_explorer_available_orders(data: &mut Data) {
 	sync_regions_with_explorer(data);
 
 	if let Some(explorer) = data.realm.expedition.explorers.current() {
+	    data.tracked = SelectionStorage::new();
+	    if let Some(explorer_region) = explorer.region {
+	    	data.tracked = SelectionStorage::new_from(&tracking::tracks_in(explorer_region, &data.realm.expedition.explorers, data.realm.age).into_iter()
+	    		.filter(|tracked| *tracked != explorer.id)
+	    		.collect());
+	    }
+
+	    data.characters = SelectionStorage::new_from(&dialog::present(explorer, &data.realm.characters));
+	    data.reports = SelectionStorage::new_from(&investigation::reports(explorer, &data.realm.characters));
+	    data.deliveries = SelectionStorage::new_from(&delivery::carried(explorer));
+
 	    if explorer.region.is_some() {
-	    	data.explorer_orders = SelectionStorage::new_from(&vec![ExplorerOrders::Inventory, ExplorerOrders::Actions, ExplorerOrders::Move]);
+	    	let mut orders = vec![ExplorerOrders::Inventory, ExplorerOrders::Actions, ExplorerOrders::Craft, ExplorerOrders::Move];
+	    	if explorer.has_trait(&ExplorerTrait::Ranger) && data.tracked.current().is_some() {
+	    		orders.push(ExplorerOrders::Follow);
+	    	}
+	    	if data.characters.current().is_some() {
+	    		orders.push(ExplorerOrders::Talk);
+	    	}
+	    	if data.reports.current().is_some() {
+	    		orders.push(ExplorerOrders::Report);
+	    	}
+	    	if data.deliveries.current().is_some() {
+	    		orders.push(ExplorerOrders::Deliveries);
+	    	}
+	    	data.explorer_orders = SelectionStorage::new_from(&orders);
 	    } else {
 	    	data.explorer_orders = SelectionStorage::new_from(&vec![ExplorerOrders::Inventory, ExplorerOrders::Embark]);
 	    }
+
+	    data.recipes = SelectionStorage::new();
+	    data.actions = SelectionStorage::new();
+	    data.buildings = SelectionStorage::new();
+	    data.recipients = SelectionStorage::new_from(&data.realm.expedition.explorers.iter()
+	    	.filter(|recipient| recipient.id != explorer.id && recipient.region.is_some() && recipient.region == explorer.region)
+	    	.map(|recipient| recipient.id)
+	    	.collect());
+	    if let Some(explorer_region) = explorer.region {
+	    	if let Some(region) = data.realm.island.regions.storage().get(&explorer_region) {
+	    		data.recipes = SelectionStorage::new_from(&crafting::available(explorer, region));
+	    		// the buildings get a menu of their own, listed once among the actions
+	    		let mut listed: Vec<ExplorerAction> = vec![];
+	    		let mut buildings = vec![];
+	    		for action in actions::available(explorer, region, &data.realm.island.regions) {
+	    			if let ExplorerAction::Build(building) = action {
+	    				buildings.push(building);
+	    				if buildings.len() > 1 {
+	    					continue;
+	    				}
+	    			}
+	    			listed.push(action);
+	    		}
+	    		data.actions = SelectionStorage::new_from(&listed);
+	    		data.buildings = SelectionStorage::new_from(&buildings);
+	    	}
+	    }
 	}
 }
 
-fn explorer_action(stream: &mut TcpStream, client: ClientId, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> RealmsProtocol {
-	let mut request = RealmsProtocol::Void;
+fn update_dialog_choices(data: &mut Data) {
+	data.choices = SelectionStorage::new();
 
-	if let Some(region) = regions.current() {
+	if let (Some(explorer), Some(character)) = (data.realm.expedition.explorers.current(), data.characters.current()) {
+		if let Some(character) = data.realm.characters.get(*character) {
+			data.choices = SelectionStorage::new_from(&dialog::choices(character, explorer, &data.realm.completed));
+		}
+	}
+}
+
+fn explorer_action(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, actions: &SelectionStorage<ExplorerAction>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(action) = actions.current() {
+		if let Some(explorer) = explorers.current() {
+			if let Some(region_id) = explorer.region {
+				request = send_order(client, journal, RealmsProtocol::Explorer(Move::Action(realm_id, region_id, explorer.id, action.clone())));
+			}
+		}
+	}
+
+	request
+}
+
+fn explorer_build(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, buildings: &SelectionStorage<Building>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(building) = buildings.current() {
+		if let Some(explorer) = explorers.current() {
+			if let Some(region_id) = explorer.region {
+				request = send_order(client, journal, RealmsProtocol::Explorer(Move::Action(realm_id, region_id, explorer.id, ExplorerAction::Build(*building))));
+			}
+		}
+	}
+
+	request
+}
+
+fn explorer_give(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, recipients: &SelectionStorage<ExplorerId>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(recipient) = recipients.current() {
+		if let Some(explorer) = explorers.current() {
+			if let Some(item) = explorer.inventory.current() {
+				request = send_order(client, journal, RealmsProtocol::GiveItem(realm_id, explorer.id, *recipient, item.clone()));
+			}
+		}
+	}
+
+	request
+}
+
+fn explorer_follow(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, tracked: &SelectionStorage<ExplorerId>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(tracked) = tracked.current() {
+		if let Some(explorer) = explorers.current() {
+			request = send_order(client, journal, RealmsProtocol::Explorer(Move::Follow(realm_id, explorer.id, *tracked)));
+		}
+	}
+
+	request
+}
+
+fn explorer_talk(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, characters: &SelectionStorage<CharacterId>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(character) = characters.current() {
+		if let Some(explorer) = explorers.current() {
+			request = send_order(client, journal, RealmsProtocol::Talk(realm_id, explorer.id, *character));
+		}
+	}
+
+	request
+}
+
+fn explorer_report(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, reports: &SelectionStorage<(CharacterId, Finding)>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some((character, finding)) = reports.current() {
+		if let Some(explorer) = explorers.current() {
+			request = send_order(client, journal, RealmsProtocol::Report(realm_id, explorer.id, *character, *finding));
+		}
+	}
+
+	request
+}
+
+fn explorer_deliver(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, deliveries: &SelectionStorage<Delivery>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(delivery) = deliveries.current() {
+		if let Some(explorer) = explorers.current() {
+			request = send_order(client, journal, RealmsProtocol::Deliver(realm_id, explorer.id, delivery.clone()));
+		}
+	}
+
+	request
+}
+
+fn explorer_answer(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, characters: &SelectionStorage<CharacterId>, choices: &SelectionStorage<usize>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let (Some(character), Some(choice)) = (characters.current(), choices.current()) {
+		if let Some(explorer) = explorers.current() {
+			request = send_order(client, journal, RealmsProtocol::Answer(realm_id, explorer.id, *character, *choice));
+		}
+	}
+
+	request
+}
+
+fn explorer_craft(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, recipes: &SelectionStorage<Recipe>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
+
+	if let Some(recipe) = recipes.current() {
 		if let Some(explorer) = explorers.current() {
-			if let Some(action) = explorer.trait_actions().first() {
-				request = send_request(stream, client, RealmsProtocol::Explorer(Move::Action(realm_id, region.id, explorer.id, action.clone())));
+			if let Some(region_id) = explorer.region {
+				request = send_order(client, journal, RealmsProtocol::Craft(realm_id, region_id, explorer.id, recipe.id));
 			}
 		}
 	}
@@ -463,17 +1207,17 @@ fn explorer_action(stream: &mut TcpStream, client: ClientId, realm_id: RealmId,
 	request
 }
 
-fn explorer_drop(stream: &mut TcpStream, client: ClientId, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> RealmsProtocol {
-	let mut request = RealmsProtocol::Void;
+fn explorer_drop(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
 
 	if let Some(region) = regions.current() {
 		if let Some(explorer) = explorers.current() {
 			match explorer.inventory.current() {
 			    Some(ExplorerItem::Equipment(item)) => {
-			    	request = send_request(stream, client, RealmsProtocol::DropEquipment(realm_id, region.id, explorer.id, item.clone()));
+			    	request = send_order(client, journal, RealmsProtocol::DropEquipment(realm_id, region.id, explorer.id, *item));
 			    },
 			    Some(ExplorerItem::Particularity(region_id, particularity)) => {
-			    	request = send_request(stream, client, RealmsProtocol::ForgetParticularity(realm_id, *region_id, explorer.id, particularity.clone()));	
+			    	request = send_order(client, journal, RealmsProtocol::ForgetParticularity(realm_id, *region_id, explorer.id, *particularity));	
 			    },
 			    _ => { }
 			}
@@ -483,17 +1227,17 @@ fn explorer_drop(stream: &mut TcpStream, client: ClientId, realm_id: RealmId, re
 	request
 }
 
-fn explorer_handle_particularity(stream: &mut TcpStream, client: ClientId, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> RealmsProtocol {
-	let mut request = RealmsProtocol::Void;
+fn explorer_handle_particularity(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
 
 	if let Some(region) = regions.current() {
 		if let Some(explorer) = explorers.current() {
 			match region.particularities.current() {
 			    Some(Particularity::Item(item)) => {
-			    	request = send_request(stream, client, RealmsProtocol::PickEquipment(realm_id, region.id, explorer.id, item.clone()));
+			    	request = send_order(client, journal, RealmsProtocol::PickEquipment(realm_id, region.id, explorer.id, *item));
 			    },
 			    Some(particularity) => {
-			    	request = send_request(stream, client, RealmsProtocol::InvestigateParticularity(realm_id, region.id, explorer.id, particularity.clone()));
+			    	request = send_order(client, journal, RealmsProtocol::InvestigateParticularity(realm_id, region.id, explorer.id, *particularity));
 			    },
 			    _ => { }
 			}
@@ -503,12 +1247,12 @@ fn explorer_handle_particularity(stream: &mut TcpStream, client: ClientId, realm
 	request
 }
 
-fn explorer_move(stream: &mut TcpStream, client: ClientId, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> RealmsProtocol {
-	let mut request = RealmsProtocol::Void;
+fn explorer_move(client: &mut RealmsClient, journal: &mut Journal, realm_id: RealmId, regions: &mut SelectionHashMap<Region>, explorers: &mut SelectionStorage<Explorer>) -> Result<Realm, String> {
+	let mut request = Err("nothing is selected.".to_string());
 
 	if let Some(region) = regions.current() {
 		if let Some(explorer) = explorers.current() {
-			request = send_request(stream, client, RealmsProtocol::Explorer(Move::ChangeRegion(realm_id, region.id, explorer.id)));
+			request = send_order(client, journal, RealmsProtocol::Explorer(Move::ChangeRegion(realm_id, region.id, explorer.id)));
 		}
 	}
 
@@ -0,0 +1,194 @@
+use uuid::Uuid;
+
+use tokens::*;
+use utility::*;
+use transport::{self, Transport};
+use server::{LocalServer, Universe};
+
+// called with every request and the server's answer to it
+pub type Subscriber = Box<dyn FnMut(&RealmsProtocol, &RealmsProtocol)>;
+
+// a connection to a realms universe, used by the terminal client, the scripts and anyone depending on the crate.
+// orders answer with the realm as it is after the order, a rejection is an error with the reason given by the server.
+pub struct RealmsClient {
+	transport: Box<dyn Transport>,
+	id: ClientId,
+	subscribers: Vec<Subscriber>
+}
+
+impl RealmsClient {
+	// the client has no id the server knows of until it registers or connects
+	pub fn new<T: Transport + 'static>(transport: T) -> RealmsClient {
+		RealmsClient::with(Box::new(transport))
+	}
+
+	fn with(transport: Box<dyn Transport>) -> RealmsClient {
+		RealmsClient {
+			transport,
+			id: Uuid::new_v4(),
+			subscribers: vec![]
+		}
+	}
+
+	// a server at an address like 127.0.0.1:8080 or at a socket file like unix:/tmp/realms.sock
+	pub fn open(host: &str) -> Result<RealmsClient, String> {
+		transport::connect(host).map(RealmsClient::with)
+	}
+
+	// plays against a universe of its own instead of a server, which registers the client anew every time
+	pub fn offline() -> RealmsClient {
+		RealmsClient::new(LocalServer::start(Universe::new()).connect())
+	}
+
+	pub fn id(&self) -> ClientId {
+		self.id
+	}
+
+	pub fn subscribe<F: FnMut(&RealmsProtocol, &RealmsProtocol) + 'static>(&mut self, subscriber: F) {
+		self.subscribers.push(Box::new(subscriber));
+	}
+
+	// sends any request and hands back whatever the server answered
+	pub fn send(&mut self, request: RealmsProtocol) -> Result<RealmsProtocol, String> {
+		let response = self.transport.exchange(self.id, &request)?;
+
+		for subscriber in &mut self.subscribers {
+			subscriber(&request, &response);
+		}
+
+		Ok(response)
+	}
+
+	pub fn register(&mut self) -> Result<ClientId, String> {
+		let response = self.send(RealmsProtocol::Register)?;
+		self.identified(response)
+	}
+
+	// a server not knowing the id registers the client anew
+	pub fn connect(&mut self, id: ClientId) -> Result<ClientId, String> {
+		self.id = id;
+		let response = self.send(RealmsProtocol::Connect(id))?;
+		self.identified(response)
+	}
+
+	fn identified(&mut self, response: RealmsProtocol) -> Result<ClientId, String> {
+		match response {
+			RealmsProtocol::Connect(id) => {
+				self.id = id;
+				Ok(id)
+			},
+			response => Err(unexpected(response))
+		}
+	}
+
+	pub fn quit(&mut self) -> Result<(), String> {
+		match self.send(RealmsProtocol::Quit)? {
+			RealmsProtocol::Quit => {
+				self.transport.close();
+				Ok(())
+			},
+			response => Err(unexpected(response))
+		}
+	}
+
+	pub fn realms(&mut self) -> Result<SelectionStorage<RealmSummary>, String> {
+		let response = self.send(RealmsProtocol::RequestRealmsList)?;
+		realms_list(response)
+	}
+
+	pub fn new_realm(&mut self) -> Result<Realm, String> {
+		self.order(RealmsProtocol::RequestNewRealm)
+	}
+
+	pub fn realm(&mut self, realm: RealmId) -> Result<Realm, String> {
+		self.order(RealmsProtocol::RequestRealm(realm))
+	}
+
+	pub fn archive_realm(&mut self, realm: RealmId) -> Result<SelectionStorage<RealmSummary>, String> {
+		let response = self.send(RealmsProtocol::ArchiveRealm(realm))?;
+		realms_list(response)
+	}
+
+	pub fn delete_realm(&mut self, realm: RealmId) -> Result<SelectionStorage<RealmSummary>, String> {
+		let response = self.send(RealmsProtocol::DeleteRealm(realm))?;
+		realms_list(response)
+	}
+
+	pub fn duplicate_realm(&mut self, realm: RealmId) -> Result<SelectionStorage<RealmSummary>, String> {
+		let response = self.send(RealmsProtocol::DuplicateRealm(realm))?;
+		realms_list(response)
+	}
+
+	// sends any request answered with a realm
+	pub fn order(&mut self, request: RealmsProtocol) -> Result<Realm, String> {
+		match self.send(request)? {
+			RealmsProtocol::Realm(realm) => Ok(realm),
+			RealmsProtocol::Rejected(reason) => Err(reason),
+			response => Err(unexpected(response))
+		}
+	}
+
+	pub fn change_region(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Explorer(Move::ChangeRegion(realm, region, explorer)))
+	}
+
+	pub fn follow(&mut self, realm: RealmId, explorer: ExplorerId, tracked: ExplorerId) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Explorer(Move::Follow(realm, explorer, tracked)))
+	}
+
+	pub fn act(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId, action: ExplorerAction) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Explorer(Move::Action(realm, region, explorer, action)))
+	}
+
+	pub fn drop_equipment(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId, equipment: Equipment) -> Result<Realm, String> {
+		self.order(RealmsProtocol::DropEquipment(realm, region, explorer, equipment))
+	}
+
+	pub fn pick_equipment(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId, equipment: Equipment) -> Result<Realm, String> {
+		self.order(RealmsProtocol::PickEquipment(realm, region, explorer, equipment))
+	}
+
+	pub fn investigate(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId, particularity: Particularity) -> Result<Realm, String> {
+		self.order(RealmsProtocol::InvestigateParticularity(realm, region, explorer, particularity))
+	}
+
+	pub fn forget(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId, particularity: Particularity) -> Result<Realm, String> {
+		self.order(RealmsProtocol::ForgetParticularity(realm, region, explorer, particularity))
+	}
+
+	pub fn craft(&mut self, realm: RealmId, region: RegionId, explorer: ExplorerId, recipe: RecipeId) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Craft(realm, region, explorer, recipe))
+	}
+
+	pub fn give(&mut self, realm: RealmId, explorer: ExplorerId, recipient: ExplorerId, item: ExplorerItem) -> Result<Realm, String> {
+		self.order(RealmsProtocol::GiveItem(realm, explorer, recipient, item))
+	}
+
+	pub fn talk(&mut self, realm: RealmId, explorer: ExplorerId, character: CharacterId) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Talk(realm, explorer, character))
+	}
+
+	pub fn answer(&mut self, realm: RealmId, explorer: ExplorerId, character: CharacterId, choice: usize) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Answer(realm, explorer, character, choice))
+	}
+
+	pub fn report(&mut self, realm: RealmId, explorer: ExplorerId, character: CharacterId, finding: Finding) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Report(realm, explorer, character, finding))
+	}
+
+	pub fn deliver(&mut self, realm: RealmId, explorer: ExplorerId, delivery: Delivery) -> Result<Realm, String> {
+		self.order(RealmsProtocol::Deliver(realm, explorer, delivery))
+	}
+}
+
+fn realms_list(response: RealmsProtocol) -> Result<SelectionStorage<RealmSummary>, String> {
+	match response {
+		RealmsProtocol::RealmsList(realms) => Ok(realms),
+		RealmsProtocol::Rejected(reason) => Err(reason),
+		response => Err(unexpected(response))
+	}
+}
+
+fn unexpected(response: RealmsProtocol) -> String {
+	format!("the server answered {}.", response)
+}
@@ -1,46 +1,70 @@
 
 use std::io;
+use std::collections::BTreeMap;
 
 use tui::Terminal;
 use tui::backend::RawBackend;
 use tui::layout::{Direction, Group, Size, Rect};
-use tui::widgets::{Widget, Paragraph, Block, Borders, List, Item, SelectableList, Tabs, canvas::Canvas};
+use tui::widgets::{Widget, Paragraph, Block, Borders, List, Item, SelectableList, Tabs, Table, Row, canvas::Canvas};
 use tui::style::{Style, Color};
 use tui::widgets::canvas::Points;
 
 use client::*;
-use tokens::RegionVisibility;
+use actions;
+use tracking;
+use dialog;
+use delivery;
+use ecology;
+use weather;
+use keymap::Command;
+use journal::JournalKind;
+use browser;
+use tokens::{Region, RegionId, ExplorerId, RegionVisibility, TrailMark, EscortState, Faction, RealmSummary, ExplorerAction};
 
 pub fn draw(terminal: &mut Terminal<RawBackend>, data: &mut Data) -> Result<(), io::Error> {
 	let terminal_area = terminal.size().expect("could not get terminal size.");
 			
 	Group::default()
         .direction(Direction::Vertical)
-		.sizes(&[Size::Fixed(2), Size::Min(0)])
+		.sizes(&[Size::Fixed(2), Size::Min(0), Size::Fixed(1)])
         .render(terminal, &terminal_area, |t, chunks| {
         	draw_header(t, &chunks[0], &data);
             draw_tabs(t, &chunks[1], &data);
+            draw_footer(t, &chunks[2], data);
         });
 	// end Group::default()
 
+	if data.help {
+		draw_help(terminal, &terminal_area, data);
+	}
+
     terminal.draw()
 }
 
 fn draw_header(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+	let keys = &data.keymap;
 	Group::default()
         .direction(Direction::Horizontal)
 		.sizes(&[Size::Percent(35), Size::Percent(65)])
         .render(t, area, |t, chunks| {
         	Paragraph::default()
 		        .text(
-		            "cursor {mod=bold ↑→↓←} exit {mod=bold q}",
+		            &format!("cursor {{mod=bold {}{}{}{}}} help {{mod=bold {}}} exit {{mod=bold {}}}",
+		            	keys.key(Command::Up), keys.key(Command::Right), keys.key(Command::Down), keys.key(Command::Left), keys.key(Command::Help), keys.key(Command::Quit)),
 		        ).block(Block::default())
 		        .render(t, &chunks[0]);
     		// end Paragraph::default()
 
+        	// the weather is only known where the expedition can see it right now
+        	let age = data.realm.age;
+        	let weather = match data.realm.island.regions.current() {
+        		Some(region) if region.sight >= RegionVisibility::Partial => format!(", {:?} in region {}", region.weather, region.id),
+        		_ => String::new()
+        	};
         	Paragraph::default()
 		        .text(
-		            &format!("Client {{mod=bold {}}}", data.id),
+		            &format!("{{mod=bold {}}} day {} {:?}, {:?}{} Client {{mod=bold {}}}",
+		            	data.realm.title, weather::day(age), weather::daytime(age), ecology::season(age), weather, data.id),
 		        ).block(Block::default())
                 .wrap(true)
 		        .render(t, &chunks[1]);
@@ -49,6 +73,43 @@ fn draw_header(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
 	// end Group::default()
 }
 
+// hints for the focused panel
+fn draw_footer(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+	let hints: Vec<String> = commands(&data.active).iter()
+		.map(|(command, description)| format!("{{mod=bold {}}} {}", data.keymap.key(*command), description))
+		.collect();
+
+	Paragraph::default()
+        .text(&hints.join("  "))
+        .block(Block::default())
+        .render(t, area);
+	// end Paragraph::default()
+}
+
+// lists everything the focused panel reacts to over the middle of the screen
+fn draw_help(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+	let commands = commands(&data.active);
+	let width = (area.width * 3 / 5).max(20).min(area.width);
+	let height = (commands.len() as u16 + 2).min(area.height);
+	let help_area = Rect::new(area.x + (area.width - width) / 2, area.y + (area.height - height) / 2, width, height);
+
+	// the lines are padded to the full width to cover whatever is drawn below
+	let inner = width.saturating_sub(2) as usize;
+	let lines: Vec<String> = commands.iter()
+		.map(|(command, description)| format!("{:>6}  {:<width$}", data.keymap.key(*command), description, width = inner.saturating_sub(8)))
+		.collect();
+	let bold = Style::default().fg(Color::Yellow);
+
+	List::new(lines.iter().map(|line| Item::StyledData(line, &bold)))
+		.block(Block::default()
+			.borders(Borders::ALL)
+			.title(&format!("Help {:?} [any key to close]", data.active))
+			.border_style(bold)
+			.title_style(bold))
+		.render(t, &help_area);
+	// end List::new()
+}
+
 fn draw_tabs(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
 
     Group::default()
@@ -72,6 +133,9 @@ fn draw_tabs(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
                 1 => {
                     draw_realms_list(t, &chunks[1], &data);
                 },
+                2 => {
+                    draw_journal(t, &chunks[1], data);
+                },
                 _ => {
                     draw_regions_list(t, &chunks[1], &data);
                 }
@@ -85,32 +149,115 @@ fn draw_tabs(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
 
 fn draw_realms_list(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
 	Group::default()
-        .direction(Direction::Vertical)
-		.sizes(&[Size::Min(0)])
+        .direction(Direction::Horizontal)
+		.sizes(&[Size::Percent(60), Size::Percent(40)])
         .render(t, area, |t, chunks| {
 
         	let border_style = Style::default().fg(Color::Yellow);
+        	let style = Style::default();
+        	let selected = Style::default().fg(Color::Yellow);
+        	let done = Style::default().fg(Color::Green);
+        	let archived = Style::default().fg(Color::DarkGray);
 
         	let realms_index = data.realms.current_index();
-        	let realms: Vec<String> = data.realms.iter().map(|realm| {
-                format!("{}", realm)
+        	let realms: Vec<(Vec<String>, &Style)> = data.realms.iter().enumerate().map(|(index, summary)| {
+        		let row = vec![
+        			format!("{}", summary.id),
+        			summary.title.clone(),
+        			format!("{:?}", summary.variant),
+        			format!("{}", summary.age),
+        			format!("{}/{}", summary.completed, summary.objectives),
+        			summary.played.clone().unwrap_or_else(|| "never".to_string())
+        		];
+        		let row_style = match summary {
+        			_ if index == realms_index => &selected,
+        			RealmSummary {archived: true, ..} => &archived,
+        			RealmSummary {done: true, ..} => &done,
+        			_ => &style
+        		};
+        		(row, row_style)
             }).collect();
 
-            SelectableList::default()
-                .block(Block::default().borders(Borders::ALL).title("Realms")
+            Table::new(
+                ["rId", "title", "variant", "age", "goals", "played"].iter(),
+                realms.iter().map(|(row, row_style)| Row::StyledData(row.iter(), row_style))
+            ).block(Block::default().borders(Borders::ALL).title("Realms")
             	.border_style(border_style))
-                .items(&realms)
-                .select(realms_index)
-                .highlight_style(
-                    Style::default().fg(Color::Yellow),
-                )
-                .highlight_symbol("→")
+                .header_style(Style::default().fg(Color::Yellow))
+                .widths(&[4, 12, 9, 5, 6, 13])
                 .render(t, &chunks[0]);
-    		// end SelectableList::default()
+    		// end Table::new()
+
+    		let keys = &data.keymap;
+    		let details = match data.realms.current() {
+    			Some(summary) => {
+    				let status = match summary {
+    					RealmSummary {archived: true, ..} => "archived",
+    					RealmSummary {done: true, ..} => "done",
+    					_ => "underway"
+    				};
+    				let mut details = format!("{{mod=bold {}}}\n\n{:?} realm, {}\nday {} in {:?}, age {}\n{} of {} objectives completed\nlast played {}\nseed {}\n\n{} resume {} archive {} duplicate {} delete",
+    					summary.title, summary.variant, status,
+    					weather::day(summary.age), ecology::season(summary.age), summary.age,
+    					summary.completed, summary.objectives,
+    					summary.played.clone().unwrap_or_else(|| "never".to_string()),
+    					summary.seed,
+    					keys.key(Command::Select), keys.key(Command::ArchiveRealm), keys.key(Command::DuplicateRealm), keys.key(Command::DeleteRealm));
+    				if data.deleting == Some(summary.id) {
+    					details.push_str(&format!("\n\n{{fg=red press {} again to delete realm {} for good}}", keys.key(Command::DeleteRealm), summary.id));
+    				}
+    				details
+    			},
+    			None => format!("no realms yet, {} starts one.", keys.key(Command::NewRealm))
+    		};
+
+    		Paragraph::default()
+    			.text(&details)
+    			.block(Block::default().borders(Borders::ALL).title("Realm"))
+    			.wrap(true)
+    			.render(t, &chunks[1]);
+    		// end Paragraph::default()
         });
     // end Group::default()
 }
 
+// the entries up to the selected one, as many as fit
+fn draw_journal(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+    let styles = [
+        (JournalKind::Order, Style::default()),
+        (JournalKind::Done, Style::default().fg(Color::Green)),
+        (JournalKind::Rejected, Style::default().fg(Color::Red)),
+        (JournalKind::Objective, Style::default().fg(Color::Yellow)),
+        (JournalKind::Story, Style::default().fg(Color::Cyan)),
+        (JournalKind::Event, Style::default().fg(Color::Magenta))
+    ];
+    let selected = Style::default().fg(Color::Yellow);
+
+    let index = data.journal.entries.current_index();
+    let height = area.height.saturating_sub(2) as usize;
+    let lines: Vec<(String, &Style)> = data.journal.entries.iter().enumerate()
+        .take(index + 1)
+        .skip((index + 1).saturating_sub(height))
+        .map(|(position, entry)| {
+            let style = styles.iter().find(|(kind, _)| *kind == entry.kind).map(|(_, style)| style).unwrap_or(&styles[0].1);
+            match entry.kind {
+                JournalKind::Order if position == index => (format!("→ age {:>3} {}", entry.age, entry.text), &selected),
+                JournalKind::Order => (format!("  age {:>3} {}", entry.age, entry.text), style),
+                _ if position == index => (format!("→         {}", entry.text), &selected),
+                _ => (format!("          {}", entry.text), style)
+            }
+        })
+        .collect();
+
+    List::new(lines.iter().map(|(line, style)| Item::StyledData(line, style)))
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(&format!("Journal of realm {}", data.journal.realm))
+            .border_style(Style::default().fg(Color::Yellow)))
+        .render(t, area);
+    // end List::new()
+}
+
 fn draw_realm_ui(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
 	Group::default()
         .direction(Direction::Vertical)
@@ -155,7 +302,10 @@ fn draw_realm_objectives(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data)
         border_style = Style::default().fg(Color::Green);
     }
 
-    let objectives = data.realm.objectives.iter().map(|objective| {
+    let escorting = Style::default().fg(Color::Cyan);
+    let dimmed = Style::default().fg(Color::DarkGray);
+
+    let mut objectives: Vec<Item<String>> = data.realm.objectives.iter().map(|objective| {
         if data.realm.completed.contains(objective) {
             Item::StyledData(
                 format!("{}", objective),
@@ -167,9 +317,31 @@ fn draw_realm_objectives(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data)
                 &style
             )
         }
-    });
+    }).collect();
 
-    List::new(objectives)
+    for escort in &data.realm.escorts {
+        let name = match data.realm.characters.get(escort.character) {
+            Some(character) => character.name.clone(),
+            None => format!("{}", escort.character)
+        };
+        let state = match escort.state {
+            EscortState::Underway => &escorting,
+            EscortState::Disoriented => &dimmed,
+            EscortState::SafeAndSound => &done
+        };
+        objectives.push(Item::StyledData(
+            format!("explorer {} escorts {} to region {}, {:?}", escort.explorer, name, escort.destination, escort.state),
+            state
+        ));
+        if let Some(entry) = escort.log.last() {
+            objectives.push(Item::StyledData(
+                entry.clone(),
+                &dimmed
+            ));
+        }
+    }
+
+    List::new(objectives.into_iter())
         .block(Block::default()
             .title(&data.realm.title)
             .borders(Borders::ALL)
@@ -195,6 +367,9 @@ fn draw_realm_region_info(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data
     let style = Style::default();
     let cyan = Style::default().fg(Color::Cyan);
     let green = Style::default().fg(Color::Green);
+    let yellow = Style::default().fg(Color::Yellow);
+    let dimmed = Style::default().fg(Color::DarkGray);
+    let red = Style::default().fg(Color::Red);
 
     let mut info = vec![];
     match region.sight {
@@ -204,13 +379,29 @@ fn draw_realm_region_info(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data
                     &cyan
             ));
         },
+        RegionVisibility::Remembered => {
+            info.push(Item::StyledData(
+                    format!("Remembered from age {}.", region.seen.unwrap_or(0)),
+                    &dimmed
+            ));
+            info.push(Item::StyledData(
+                    format_buildings(region),
+                    &dimmed
+            ));
+        },
+        RegionVisibility::Mapped => {
+            info.push(Item::StyledData(
+                    "Known from a map.".to_string(),
+                    &cyan
+            ));
+        },
         RegionVisibility::Partial => {
             info.push(Item::StyledData(
                     format!("Far away."),
                     &cyan
             ));
             info.push(Item::StyledData(
-                    format!("{:?}", region.buildings.storage()),
+                    format_buildings(region),
                     &style
             ));
         },
@@ -220,12 +411,12 @@ fn draw_realm_region_info(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data
                     &cyan
             ));
             info.push(Item::StyledData(
-                    format!("{:?}", region.buildings.storage()),
+                    format_buildings(region),
                     &style
             ));
 
             info.push(Item::StyledData(
-                    format!("Resources {}", region.resources),
+                    format!("Resources {}/{}", region.resources, ecology::capacity(region)),
                     &style
             ));
         }
@@ -235,21 +426,57 @@ fn draw_realm_region_info(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data
                     &cyan
             ));
             info.push(Item::StyledData(
-                    format!("{:?}", region.buildings.storage()),
+                    format_buildings(region),
                     &style
             ));
 
             info.push(Item::StyledData(
-                    format!("Resources {}", region.resources),
+                    format!("Resources {}/{}", region.resources, ecology::capacity(region)),
                     &style
             ));
         }
     }
-    if region.mapped {
+    if region.sight >= RegionVisibility::Partial {
+        info.push(Item::StyledData(
+                format!("Weather {:?}, entering takes {} ages", region.weather, weather::movement(region, data.realm.age)),
+                &style
+        ));
+        if weather::flooded(region) {
+            info.push(Item::StyledData(
+                    "The river is flooded.".to_string(),
+                    &yellow
+            ));
+        }
+    }
+    if region.depletion > 0 && region.sight >= RegionVisibility::Complete {
         info.push(Item::StyledData(
-                format!("Mapped"),
+                "Overhunted, regrowing slowly".to_string(),
+                &yellow
+        ));
+    }
+    if let Some(owner) = region.owner {
+        info.push(Item::StyledData(
+                format!("Held by {:?}", owner),
+                &style
+        ));
+    }
+    if let Some(ref blockade) = region.blockade {
+        info.push(Item::StyledData(
+                format!("Blockade of the {:?}, strength {}, toll {:?}", blockade.faction, blockade.strength, blockade.toll),
+                &red
+        ));
+    }
+    if let Some(ref survey) = region.mapped {
+        info.push(Item::StyledData(
+                format!("Mapped at age {}", survey.age),
                 &green
         ));
+        if region.sight != RegionVisibility::Mapped && survey.particularities != *region.particularities.storage() {
+            info.push(Item::StyledData(
+                    "The map is outdated.".to_string(),
+                    &yellow
+            ));
+        }
     }
     for explorer in data.realm.expedition.explorers.iter() {
         if let Some(explorer_region) = explorer.region {
@@ -261,6 +488,25 @@ fn draw_realm_region_info(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data
             }
         }
     }
+    if region.sight >= RegionVisibility::Complete {
+        for character in data.realm.characters.iter().filter(|character| character.region == region.id) {
+            info.push(Item::StyledData(
+                    format!("{}, affection {}", character.name, character.affection),
+                    &yellow
+            ));
+        }
+    }
+    for explorer in data.realm.expedition.explorers.iter() {
+        let visits: Vec<&TrailMark> = tracking::visible_tracks(explorer, data.realm.age).into_iter()
+            .filter(|mark| mark.region == region.id)
+            .collect();
+        if let Some(mark) = visits.last() {
+            info.push(Item::StyledData(
+                    format!("Tracks of explorer {} from age {}, {} actions", explorer.id, mark.age, mark.actions.len()),
+                    &dimmed
+            ));
+        }
+    }
 
     List::new(info.into_iter())
         .block(Block::default().borders(Borders::ALL).title(&format!("{} {}", "Region", region)))
@@ -268,6 +514,13 @@ fn draw_realm_region_info(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data
     // end List::new()
 }
 
+fn format_buildings(region: &Region) -> String {
+    let buildings: Vec<String> = region.buildings.iter().map(|construction| {
+        format!("{}", construction)
+    }).collect();
+    buildings.join(", ")
+}
+
 fn draw_realm_region_particularities(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
     let region = data.realm.island.regions.current().expect("could not fetch current regions selection.");
 
@@ -277,13 +530,20 @@ fn draw_realm_region_particularities(t: &mut Terminal<RawBackend>, area: &Rect,
         format!("{:?}", particularity)
     }).collect();
 
+    // remembered particularities may have changed since
+    let mut style = Style::default();
+    if region.sight == RegionVisibility::Remembered {
+        style = Style::default().fg(Color::DarkGray);
+    }
+
     match data.active {
         InteractiveUi::Particularities => {
             SelectableList::default()
-                .block(Block::default().borders(Borders::ALL).title("Particularities [Enter pick/investigate]")
+                .block(Block::default().borders(Borders::ALL).title(&format!("Particularities [{} pick/investigate]", data.keymap.key(Command::Select)))
                 .border_style(Style::default().fg(Color::Yellow)))
                 .items(&particularities)
                 .select(particularities_index)
+                .style(style)
                 .highlight_style(
                     Style::default().fg(Color::Yellow)
                 )
@@ -297,6 +557,7 @@ fn draw_realm_region_particularities(t: &mut Terminal<RawBackend>, area: &Rect,
                 .border_style(Style::default()))
                 .items(&particularities)
                 .select(particularities_index)
+                .style(style)
                 .render(t, area);
             // end SelectableList::default()
         }
@@ -316,6 +577,7 @@ fn draw_realm_expedition(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data)
 }
 
 fn draw_realm_expedition_list(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+    let keys = &data.keymap;
 
     let explorer_index = data.realm.expedition.explorers.current_index();
     let explorers: Vec<String> = data.realm.expedition.explorers.iter().map(|explorer| {
@@ -329,7 +591,7 @@ fn draw_realm_expedition_list(t: &mut Terminal<RawBackend>, area: &Rect, data: &
     match data.active {
         InteractiveUi::Explorers => {
             SelectableList::default()
-                .block(Block::default().borders(Borders::ALL).title("Expedition [Enter]").border_style(Style::default().fg(Color::Yellow)))
+                .block(Block::default().borders(Borders::ALL).title(&format!("Expedition [{} orders, {} actions, {} inventory, {} move, {} craft]", keys.key(Command::Select), keys.key(Command::Actions), keys.key(Command::Inventory), keys.key(Command::Move), keys.key(Command::Craft))).border_style(Style::default().fg(Color::Yellow)))
                 .items(&explorers)
                 .select(explorer_index)
                 .highlight_style(
@@ -338,7 +600,7 @@ fn draw_realm_expedition_list(t: &mut Terminal<RawBackend>, area: &Rect, data: &
                 .render(t, area);
             // end SelectableList::default()
         },
-        InteractiveUi::ExplorerMove | InteractiveUi::ExplorerActions | InteractiveUi::ExplorerInventory | InteractiveUi::ExplorerOrders => {
+        InteractiveUi::ExplorerMove | InteractiveUi::ExplorerActions | InteractiveUi::ExplorerBuild | InteractiveUi::ExplorerInventory | InteractiveUi::ExplorerCraft | InteractiveUi::ExplorerGive | InteractiveUi::ExplorerFollow | InteractiveUi::ExplorerTalk | InteractiveUi::ExplorerReport | InteractiveUi::ExplorerDeliveries | InteractiveUi::Dialog | InteractiveUi::ExplorerOrders => {
             SelectableList::default()
                 .block(Block::default().borders(Borders::ALL).title("Expedition").border_style(Style::default()))
                 .items(&explorers)
@@ -364,6 +626,7 @@ fn draw_realm_expedition_list(t: &mut Terminal<RawBackend>, area: &Rect, data: &
 }
 
 fn draw_realm_expedition_explorer(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+    let keys = &data.keymap;
 
     let region_index = data.realm.island.regions.current_index();
     let regions: Vec<String> = data.realm.island.regions.iter().map(|region| {
@@ -376,15 +639,94 @@ fn draw_realm_expedition_explorer(t: &mut Terminal<RawBackend>, area: &Rect, dat
     }).collect();
 
 
+    let recipes_index = data.recipes.current_index();
+    let recipes: Vec<String> = data.recipes.iter().map(|recipe| {
+        format!("{}", recipe)
+    }).collect();
+
+    let actions_index = data.actions.current_index();
+    let actions: Vec<String> = data.actions.iter().map(|action| {
+        match action {
+            ExplorerAction::Build(_) => "Build …".to_string(),
+            action => format!("{}", action)
+        }
+    }).collect();
+
+    // actions are taken where the explorer stands, wherever the map cursor is
+    let explorer_region = data.realm.expedition.explorers.current()
+        .and_then(|explorer| explorer.region)
+        .and_then(|region| data.realm.island.regions.storage().get(&region));
+
+    let buildings_index = data.buildings.current_index();
+    let buildings: Vec<String> = data.buildings.iter().map(|building| {
+        let construction = explorer_region.and_then(|region| region.buildings.iter().find(|construction| construction.building == *building));
+        match construction {
+            Some(construction) => format!("{}", construction),
+            None => format!("{:?} {:?} {} turns", building, building.costs(), building.turns())
+        }
+    }).collect();
+
     let mut inventory_index = 0;
     let mut inventory: Vec<String> = vec![];
     if let Some(explorer) = data.realm.expedition.explorers.current() {
         inventory_index = explorer.inventory.current_index();
         inventory = explorer.inventory.iter().map(|item| {
-            format!("{:?}", item)
+            format!("{}", item)
         }).collect();
     }
 
+    let recipients_index = data.recipients.current_index();
+    let recipients: Vec<String> = data.recipients.iter().map(|recipient| {
+        match data.realm.expedition.explorers.storage().get(*recipient) {
+            Some(explorer) => format!("{} {:?}", explorer.id, explorer.traits.storage()),
+            None => format!("{}", recipient)
+        }
+    }).collect();
+
+    let tracked_index = data.tracked.current_index();
+    let tracked: Vec<String> = data.tracked.iter().map(|tracked| {
+        match data.realm.expedition.explorers.storage().get(*tracked) {
+            Some(explorer) => format!("{} {:?}", explorer.id, explorer.traits.storage()),
+            None => format!("{}", tracked)
+        }
+    }).collect();
+
+    let characters_index = data.characters.current_index();
+    let characters: Vec<String> = data.characters.iter().map(|character| {
+        match data.realm.characters.get(*character) {
+            Some(character) => character.name.clone(),
+            None => format!("{}", character)
+        }
+    }).collect();
+
+    let reports_index = data.reports.current_index();
+    let reports: Vec<String> = data.reports.iter().map(|(character, finding)| {
+        match data.realm.characters.get(*character) {
+            Some(character) => format!("{} to {}", finding, character.name),
+            None => format!("{} to {}", finding, character)
+        }
+    }).collect();
+
+    let deliveries_index = data.deliveries.current_index();
+    let deliveries: Vec<String> = data.deliveries.iter().map(|item| {
+        match data.realm.expedition.explorers.current() {
+            Some(explorer) if delivery::deliverable(explorer, item, &data.realm.characters).is_ok() => format!("{} [deliver]", item),
+            _ => format!("{}", item)
+        }
+    }).collect();
+
+    let mut conversation = String::new();
+    let mut choices: Vec<String> = vec![];
+    if let (Some(explorer), Some(character)) = (data.realm.expedition.explorers.current(), data.characters.current()) {
+        if let Some(character) = data.realm.characters.get(*character) {
+            conversation = format!("{{mod=bold {}}} {}\n\n", character.name, character.text);
+            if let Some(node) = dialog::current(character, explorer.id) {
+                conversation = format!("{}{}", conversation, node.text);
+                choices = data.choices.iter().map(|choice| node.choices[*choice].text.clone()).collect();
+            }
+        }
+    }
+
     match data.active {
         InteractiveUi::Explorers => {
             SelectableList::default()
@@ -401,7 +743,7 @@ fn draw_realm_expedition_explorer(t: &mut Terminal<RawBackend>, area: &Rect, dat
             SelectableList::default()
                 .block(Block::default()
                     .borders(Borders::ALL)
-                    .title("Explorer [Enter]")
+                    .title(&format!("Explorer [{}]", keys.key(Command::Select)))
                     .border_style(Style::default().fg(Color::Yellow)))
                 .items(&explorer_orders)
                 .select(explorer_orders_index)
@@ -416,7 +758,7 @@ fn draw_realm_expedition_explorer(t: &mut Terminal<RawBackend>, area: &Rect, dat
             SelectableList::default()
                 .block(Block::default()
                     .borders(Borders::ALL)
-                    .title("Inventory [Bsp to exit, Enter drop/forget]")
+                    .title(&format!("Inventory [{} to exit, {} drop/forget, {} give]", keys.key(Command::Back), keys.key(Command::Select), keys.key(Command::Give)))
                     .border_style(Style::default().fg(Color::Yellow))
                     .title_style(Style::default().fg(Color::Yellow)))
                 .items(&inventory)
@@ -429,24 +771,216 @@ fn draw_realm_expedition_explorer(t: &mut Terminal<RawBackend>, area: &Rect, dat
             // end SelectableList::default()
         },
         InteractiveUi::ExplorerActions => {
-            if let Some(explorer) = data.realm.expedition.explorers.current() {
-                Paragraph::default()
-                    .text(&format!("{:?}", explorer.trait_actions()))
-                    .block(Block::default()
-                        .borders(Borders::ALL)
-                        .title("Actions [Bsp to exit]")
-                        .border_style(Style::default().fg(Color::Yellow))
-                        .title_style(Style::default().fg(Color::Yellow)))
-                    .wrap(true)
-                    .render(t, area);
-                // end Paragraph::default() 
-            }
+            Group::default()
+                .direction(Direction::Horizontal)
+                .sizes(&[Size::Percent(50), Size::Percent(50)])
+                .render(t, area, |t, chunks| {
+                    SelectableList::default()
+                        .block(Block::default()
+                            .borders(Borders::ALL)
+                            .title(&format!("Actions [{} to exit, {} act]", keys.key(Command::Back), keys.key(Command::Select)))
+                            .border_style(Style::default().fg(Color::Yellow))
+                            .title_style(Style::default().fg(Color::Yellow)))
+                        .items(&actions)
+                        .select(actions_index)
+                        .highlight_style(
+                            Style::default().fg(Color::Yellow)
+                        )
+                        .highlight_symbol("→")
+                        .render(t, &chunks[0]);
+                    // end SelectableList::default()
+
+                    let mut details = String::new();
+                    if let Some(ExplorerAction::Build(_)) = data.actions.current() {
+                        details = format!("choose among {} buildings.", data.buildings.iter().count());
+                    } else if let (Some(action), Some(explorer), Some(region)) = (data.actions.current(), data.realm.expedition.explorers.current(), explorer_region) {
+                        details = format!(
+                            "{}\n{{mod=bold cost}} {}\n{{mod=bold outcome}} {}",
+                            actions::description(action),
+                            actions::costs(action, region),
+                            actions::preview(action, explorer, region, &data.realm.island.regions)
+                        );
+                    }
+
+                    Paragraph::default()
+                        .text(&details)
+                        .block(Block::default().borders(Borders::ALL))
+                        .wrap(true)
+                        .render(t, &chunks[1]);
+                    // end Paragraph::default()
+                });
+            // end Group::default()
+        },
+        InteractiveUi::ExplorerBuild => {
+            Group::default()
+                .direction(Direction::Horizontal)
+                .sizes(&[Size::Percent(50), Size::Percent(50)])
+                .render(t, area, |t, chunks| {
+                    SelectableList::default()
+                        .block(Block::default()
+                            .borders(Borders::ALL)
+                            .title(&format!("Build [{} to exit, {} build]", keys.key(Command::Back), keys.key(Command::Select)))
+                            .border_style(Style::default().fg(Color::Yellow))
+                            .title_style(Style::default().fg(Color::Yellow)))
+                        .items(&buildings)
+                        .select(buildings_index)
+                        .highlight_style(
+                            Style::default().fg(Color::Yellow)
+                        )
+                        .highlight_symbol("→")
+                        .render(t, &chunks[0]);
+                    // end SelectableList::default()
+
+                    let mut details = String::new();
+                    if let (Some(building), Some(explorer), Some(region)) = (data.buildings.current(), data.realm.expedition.explorers.current(), explorer_region) {
+                        let action = ExplorerAction::Build(*building);
+                        details = format!(
+                            "{}\n{{mod=bold cost}} {}\n{{mod=bold outcome}} {}",
+                            actions::description(&action),
+                            actions::costs(&action, region),
+                            actions::preview(&action, explorer, region, &data.realm.island.regions)
+                        );
+                    }
+
+                    Paragraph::default()
+                        .text(&details)
+                        .block(Block::default().borders(Borders::ALL))
+                        .wrap(true)
+                        .render(t, &chunks[1]);
+                    // end Paragraph::default()
+                });
+            // end Group::default()
+        },
+        InteractiveUi::ExplorerGive => {
+            SelectableList::default()
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(&format!("Give to [{} to exit, {} give]", keys.key(Command::Back), keys.key(Command::Select)))
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title_style(Style::default().fg(Color::Yellow)))
+                .items(&recipients)
+                .select(recipients_index)
+                .highlight_style(
+                    Style::default().fg(Color::Yellow)
+                )
+                .highlight_symbol("→")
+                .render(t, area);
+            // end SelectableList::default()
+        },
+        InteractiveUi::ExplorerFollow => {
+            SelectableList::default()
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(&format!("Follow tracks of [{} to exit, {} follow]", keys.key(Command::Back), keys.key(Command::Select)))
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title_style(Style::default().fg(Color::Yellow)))
+                .items(&tracked)
+                .select(tracked_index)
+                .highlight_style(
+                    Style::default().fg(Color::Yellow)
+                )
+                .highlight_symbol("→")
+                .render(t, area);
+            // end SelectableList::default()
+        },
+        InteractiveUi::ExplorerTalk => {
+            SelectableList::default()
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(&format!("Talk to [{} to exit, {} talk]", keys.key(Command::Back), keys.key(Command::Select)))
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title_style(Style::default().fg(Color::Yellow)))
+                .items(&characters)
+                .select(characters_index)
+                .highlight_style(
+                    Style::default().fg(Color::Yellow)
+                )
+                .highlight_symbol("→")
+                .render(t, area);
+            // end SelectableList::default()
+        },
+        InteractiveUi::ExplorerReport => {
+            SelectableList::default()
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(&format!("Report [{} to exit, {} report]", keys.key(Command::Back), keys.key(Command::Select)))
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title_style(Style::default().fg(Color::Yellow)))
+                .items(&reports)
+                .select(reports_index)
+                .highlight_style(
+                    Style::default().fg(Color::Yellow)
+                )
+                .highlight_symbol("→")
+                .render(t, area);
+            // end SelectableList::default()
+        },
+        InteractiveUi::ExplorerDeliveries => {
+            SelectableList::default()
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(&format!("Deliveries [{} to exit, {} deliver]", keys.key(Command::Back), keys.key(Command::Select)))
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title_style(Style::default().fg(Color::Yellow)))
+                .items(&deliveries)
+                .select(deliveries_index)
+                .highlight_style(
+                    Style::default().fg(Color::Yellow)
+                )
+                .highlight_symbol("→")
+                .render(t, area);
+            // end SelectableList::default()
+        },
+        InteractiveUi::Dialog => {
+            Group::default()
+                .direction(Direction::Horizontal)
+                .sizes(&[Size::Percent(50), Size::Percent(50)])
+                .render(t, area, |t, chunks| {
+                    Paragraph::default()
+                        .text(&conversation)
+                        .block(Block::default().borders(Borders::ALL))
+                        .wrap(true)
+                        .render(t, &chunks[0]);
+                    // end Paragraph::default()
+
+                    SelectableList::default()
+                        .block(Block::default()
+                            .borders(Borders::ALL)
+                            .title(&format!("Answer [{} to exit, {} answer]", keys.key(Command::Back), keys.key(Command::Select)))
+                            .border_style(Style::default().fg(Color::Yellow))
+                            .title_style(Style::default().fg(Color::Yellow)))
+                        .items(&choices)
+                        .select(data.choices.current_index())
+                        .highlight_style(
+                            Style::default().fg(Color::Yellow)
+                        )
+                        .highlight_symbol("→")
+                        .render(t, &chunks[1]);
+                    // end SelectableList::default()
+                });
+            // end Group::default()
+        },
+        InteractiveUi::ExplorerCraft => {
+            SelectableList::default()
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(&format!("Craft [{} to exit, {} craft]", keys.key(Command::Back), keys.key(Command::Select)))
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title_style(Style::default().fg(Color::Yellow)))
+                .items(&recipes)
+                .select(recipes_index)
+                .highlight_style(
+                    Style::default().fg(Color::Yellow)
+                )
+                .highlight_symbol("→")
+                .render(t, area);
+            // end SelectableList::default()
         },
         InteractiveUi::ExplorerMove => {
-            let mut title = "Move [Bsp to exit]".to_string();
+            let mut title = format!("Move [{} to exit]", keys.key(Command::Back));
             if let Some(explorer) = data.realm.expedition.explorers.current() {
                 if !explorer.region.is_some() {
-                    title = "Embark [Bsp to exit]".to_string();
+                    title = format!("Embark [{} to exit]", keys.key(Command::Back));
                 }
             }
             SelectableList::default()
@@ -477,34 +1011,190 @@ fn draw_realm_expedition_explorer(t: &mut Terminal<RawBackend>, area: &Rect, dat
 
 fn draw_regions_list(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
 
-    // todo: location details and particularities
     // todo: regions as hex map
 
     Group::default()
         .direction(Direction::Horizontal)
-        .sizes(&[Size::Min(0), Size::Fixed(30)])
+        .sizes(&[Size::Percent(55), Size::Percent(45)])
         .render(t, area, |t, chunks| {
-
-            let _border_style = Style::default().fg(Color::Yellow);
-
-            draw_realm_regions_canvas(t, &chunks[0], &data);
+            draw_regions_table(t, &chunks[0], data);
 
             Group::default()
                 .direction(Direction::Vertical)
-                .sizes(&[Size::Percent(50), Size::Percent(50)])
+                .sizes(&[Size::Min(0), Size::Fixed(12)])
                 .render(t, &chunks[1], |t, chunks| {
-                    draw_realm_region_info(t, &chunks[0], &data);
-                    draw_realm_region_particularities(t, &chunks[1], &data);
+                    draw_region_details(t, &chunks[0], data);
+                    draw_realm_regions_canvas(t, &chunks[1], &data);
                 });
             // end Group::default()
         });
     // end Group::default()
 }
 
+// steps to every region from where the selected explorer stands
+fn explorer_distances(data: &Data) -> Option<(ExplorerId, BTreeMap<RegionId, usize>)> {
+    data.realm.expedition.explorers.current()
+        .and_then(|explorer| explorer.region.map(|region| (explorer.id, browser::distances(&data.realm.island.regions, region))))
+}
+
+// the listed regions up to the selected one, as many as fit
+fn draw_regions_table(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+    let style = Style::default();
+    let selected = Style::default().fg(Color::Yellow);
+    let live = Style::default().fg(Color::Green);
+    let remembered = Style::default().fg(Color::DarkGray);
+    let unseen = Style::default().fg(Color::Cyan);
+
+    let distances = explorer_distances(data).map(|(_, distances)| distances).unwrap_or_default();
+    let index = data.browsed.current_index();
+    let height = area.height.saturating_sub(3) as usize;
+    let regions: Vec<(Vec<String>, &Style)> = data.browsed.iter().enumerate()
+        .skip((index + 1).saturating_sub(height))
+        .filter_map(|(position, id)| data.realm.island.regions.storage().get(id).map(|region| (position, region)))
+        .map(|(position, region)| {
+            let row = vec![
+                format!("{}", region.id),
+                format!("{:?}", region.terrain),
+                format!("{:?}", region.sight),
+                match browser::resources(region) {
+                    Some(resources) => format!("{}/{}", resources, ecology::capacity(region)),
+                    None => "?".to_string()
+                },
+                match region.mapped {
+                    Some(ref survey) => format!("age {}", survey.age),
+                    None => "-".to_string()
+                },
+                format!("{}", region.neighbors.len()),
+                match distances.get(&region.id) {
+                    Some(steps) => format!("{}", steps),
+                    None => "-".to_string()
+                }
+            ];
+            let row_style = match region.sight {
+                _ if position == index => &selected,
+                RegionVisibility::Live => &live,
+                RegionVisibility::Remembered => &remembered,
+                RegionVisibility::None | RegionVisibility::Mapped => &unseen,
+                _ => &style
+            };
+            (row, row_style)
+        }).collect();
+
+    let keys = &data.keymap;
+    Table::new(
+        ["rId", "terrain", "sight", "res", "mapped", "nb", "dist"].iter(),
+        regions.iter().map(|(row, row_style)| Row::StyledData(row.iter(), row_style))
+    ).block(Block::default()
+        .borders(Borders::ALL)
+        .title(&format!("Regions by {:?}, {:?} [{} sort, {} filter]", data.region_sort, data.region_filter, keys.key(Command::Sort), keys.key(Command::Filter)))
+        .border_style(Style::default().fg(Color::Yellow)))
+        .header_style(Style::default().fg(Color::Yellow))
+        .widths(&[4, 9, 11, 6, 7, 3, 4])
+        .render(t, area);
+    // end Table::new()
+}
+
+fn draw_region_details(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
+    let region = match data.browsed.current().and_then(|id| data.realm.island.regions.storage().get(id)) {
+        Some(region) => region,
+        None => {
+            Paragraph::default()
+                .text("no region fits the filter.")
+                .block(Block::default().borders(Borders::ALL).title("Region"))
+                .wrap(true)
+                .render(t, area);
+            // end Paragraph::default()
+            return;
+        }
+    };
+    let age = data.realm.age;
+
+    // what the expedition knows of the region and how fresh it is
+    let mut details = format!("{{mod=bold {}}}\n", region);
+    details.push_str(&match region.sight {
+        RegionVisibility::Live => "{fg=green live}, explorers are there.".to_string(),
+        RegionVisibility::Complete => "{fg=green live}, in view of the explorers.".to_string(),
+        RegionVisibility::Partial => "{fg=green live}, seen from afar.".to_string(),
+        RegionVisibility::Remembered => format!("{{fg=dark_gray remembered}} from age {}, {} ages ago.", region.seen.unwrap_or(0), age.saturating_sub(region.seen.unwrap_or(0))),
+        RegionVisibility::Mapped => format!("{{fg=cyan known from a map}} of age {}.", region.mapped.as_ref().map(|survey| survey.age).unwrap_or(0)),
+        RegionVisibility::None => "{fg=cyan not seen yet}.".to_string()
+    });
+    details.push('\n');
+
+    if region.sight >= RegionVisibility::Partial {
+        details.push_str(&format!("weather {:?}, entering takes {} ages\n", region.weather, weather::movement(region, age)));
+    }
+    if let Some(resources) = browser::resources(region) {
+        details.push_str(&format!("resources {}/{}\n", resources, ecology::capacity(region)));
+    }
+    match explorer_distances(data) {
+        Some((explorer, distances)) => match distances.get(&region.id) {
+            Some(steps) => details.push_str(&format!("{} regions from explorer {}\n", steps, explorer)),
+            None => details.push_str(&format!("no known way from explorer {}\n", explorer))
+        },
+        None => details.push_str("the selected explorer has not embarked yet\n")
+    }
+
+    let particularities: Vec<String> = region.particularities.iter().map(|particularity| format!("{:?}", particularity)).collect();
+    let explorers: Vec<String> = browser::occupants(region.id, &data.realm.expedition.explorers).iter()
+        .map(|explorer| format!("{} {:?}", explorer.id, explorer.traits.storage()))
+        .collect();
+    let neighbors: Vec<String> = region.neighbors.iter().map(|neighbor| format!("{}", neighbor)).collect();
+    details.push_str(&format!("\n{{mod=bold particularities}} {}\n", list_or_none(&particularities)));
+    details.push_str(&format!("{{mod=bold buildings}} {}\n", list_or_none(&[format_buildings(region)])));
+    details.push_str(&format!("{{mod=bold explorers}} {}\n", list_or_none(&explorers)));
+    if region.sight >= RegionVisibility::Complete {
+        let characters: Vec<String> = data.realm.characters.iter()
+            .filter(|character| character.region == region.id)
+            .map(|character| character.name.clone())
+            .collect();
+        details.push_str(&format!("{{mod=bold characters}} {}\n", list_or_none(&characters)));
+    }
+    details.push_str(&format!("{{mod=bold neighbors}} {}\n", list_or_none(&neighbors)));
+
+    if let Some(owner) = region.owner {
+        details.push_str(&format!("\nheld by {:?}", owner));
+    }
+    if let Some(ref blockade) = region.blockade {
+        details.push_str(&format!("\n{{fg=red blockade of the {:?}, strength {}}}", blockade.faction, blockade.strength));
+    }
+    if region.sight == RegionVisibility::Remembered {
+        details.push_str("\n{fg=dark_gray particularities and buildings may have changed since.}");
+    }
+
+    Paragraph::default()
+        .text(&details)
+        .block(Block::default().borders(Borders::ALL).title("Region"))
+        .wrap(true)
+        .render(t, area);
+    // end Paragraph::default()
+}
+
+fn list_or_none(items: &[String]) -> String {
+    let items: Vec<&str> = items.iter().map(|item| item.as_str()).filter(|item| !item.is_empty()).collect();
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
 fn draw_realm_regions_canvas(t: &mut Terminal<RawBackend>, area: &Rect, data: &Data) {
     let mut discovered_regions_array = [(0.0, 0.0);25];
     let mut neigboring_regions_array = [(0.0, 0.0);25];
+    let mut mapped_regions_array = [(0.0, 0.0);25];
+    let mut remembered_regions_array = [(0.0, 0.0);25];
     let mut active_regions_array = [(0.0, 0.0);25];
+    let mut trail_regions_array = [(0.0, 0.0);25];
+    let mut destination_regions_array = [(0.0, 0.0);25];
+    let mut claimed_regions_array = [(0.0, 0.0);25];
+    let mut blockaded_regions_array = [(0.0, 0.0);25];
+
+    // where the deliveries of the whole expedition are headed
+    let destinations: Vec<RegionId> = data.realm.expedition.explorers.iter()
+        .flat_map(delivery::carried)
+        .filter_map(|item| delivery::destination(&item, &data.realm.characters))
+        .collect();
     let mut current_region_array = [(0.0, 0.0);25];
 
     let mut current_region_id: Option<usize> = None;
@@ -522,6 +1212,12 @@ fn draw_realm_regions_canvas(t: &mut Terminal<RawBackend>, area: &Rect, data: &D
                 RegionVisibility::Complete => {
                     active_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
                 },
+                RegionVisibility::Remembered => {
+                    remembered_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
+                },
+                RegionVisibility::Mapped => {
+                    mapped_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
+                },
                 RegionVisibility::Partial => {
                     neigboring_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)  
                 },
@@ -530,6 +1226,23 @@ fn draw_realm_regions_canvas(t: &mut Terminal<RawBackend>, area: &Rect, data: &D
                 },
             }
 
+            if let Some(explorer) = data.realm.expedition.explorers.current() {
+                if tracking::visible_tracks(explorer, data.realm.age).iter().any(|mark| mark.region == region.1.id) {
+                    trail_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
+                }
+            }
+
+            if region.1.owner == Some(Faction::Expedition) {
+                claimed_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
+            }
+            if region.1.blockade.is_some() {
+                blockaded_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
+            }
+
+            if destinations.contains(&region.1.id) {
+                destination_regions_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
+            }
+
             if let Some(region_id) = current_region_id {
                 if region.1.id == region_id {
                     current_region_array[index] = (region.1.hex_offset_coords.0 as f64, region.1.hex_offset_coords.1 as f64)
@@ -550,10 +1263,34 @@ fn draw_realm_regions_canvas(t: &mut Terminal<RawBackend>, area: &Rect, data: &D
                 coords: &neigboring_regions_array,
                 color: Color::Cyan,
             });
+            ctx.draw(&Points {
+                coords: &remembered_regions_array,
+                color: Color::DarkGray,
+            });
+            ctx.draw(&Points {
+                coords: &mapped_regions_array,
+                color: Color::Blue,
+            });
             ctx.draw(&Points {
                 coords: &discovered_regions_array,
                 color: Color::White,
             });
+            ctx.draw(&Points {
+                coords: &trail_regions_array,
+                color: Color::Magenta,
+            });
+            ctx.draw(&Points {
+                coords: &claimed_regions_array,
+                color: Color::LightGreen,
+            });
+            ctx.draw(&Points {
+                coords: &blockaded_regions_array,
+                color: Color::LightRed,
+            });
+            ctx.draw(&Points {
+                coords: &destination_regions_array,
+                color: Color::Red,
+            });
             ctx.draw(&Points {
                 coords: &current_region_array,
                 color: Color::Yellow,
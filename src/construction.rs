@@ -0,0 +1,83 @@
+use tokens::*;
+
+use crafting::RegionRequirement;
+use weather;
+
+// where a building may be placed at all
+pub fn site(building: Building) -> Option<RegionRequirement> {
+    match building {
+        Building::Bridge | Building::Dam => Some(RegionRequirement::Particularity(Particularity::River)),
+        Building::Lighthouse => Some(RegionRequirement::Terrain(Terrain::Coast)),
+        _ => None
+    }
+}
+
+pub fn buildable(building: Building, explorer: &Explorer, region: &Region) -> Result<(), String> {
+    if !explorer.has_trait(&ExplorerTrait::Builder) {
+        return Err(format!("only an {} can build.", ExplorerTrait::Builder));
+    }
+    if !explorer.has_equipment(&Equipment::Tools) {
+        return Err("building needs Tools.".to_string());
+    }
+
+    match region.buildings.iter().find(|construction| construction.building == building) {
+        Some(construction) => {
+            if construction.complete() {
+                return Err(format!("there already is a {:?} here.", building));
+            }
+        },
+        None => {
+            match site(building) {
+                Some(RegionRequirement::Terrain(ref terrain)) if region.terrain != *terrain => {
+                    return Err(format!("a {:?} can only be built at {:?} regions.", building, terrain));
+                },
+                Some(RegionRequirement::Particularity(particularity)) if !region.has_particularity(particularity) => {
+                    return Err(format!("a {:?} needs a {:?}.", building, particularity));
+                },
+                _ => { }
+            }
+            for equipment in building.costs() {
+                if !explorer.has_equipment(&equipment) {
+                    return Err(format!("a {:?} needs {:?}.", building, equipment));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// starts the construction by consuming the materials or advances one already started.
+pub fn build(building: Building, explorer: &mut Explorer, region: &mut Region) -> Result<(), String> {
+    buildable(building, explorer, region)?;
+
+    if let Some(construction) = region.buildings.iter_mut().find(|construction| construction.building == building) {
+        construction.progress += 1;
+        return Ok(());
+    }
+
+    for equipment in building.costs() {
+        explorer.take_equipment(&equipment);
+    }
+    region.buildings.insert(Construction { building, progress: 1 });
+
+    Ok(())
+}
+
+// wading into a river from its banks needs a bridge or something to hold on to, a flooded one a boat.
+pub fn river_crossing(explorer: &Explorer, from: Option<&Region>, to: &Region) -> bool {
+    if !to.has_particularity(Particularity::River) || to.has_building(Building::Bridge) {
+        return true;
+    }
+
+    match from {
+        Some(from) if !from.has_particularity(Particularity::River) && weather::flooded(to) => {
+            [Equipment::Canoe, Equipment::Raft].iter().any(|equipment| explorer.has_equipment(equipment))
+        },
+        Some(from) if !from.has_particularity(Particularity::River) => {
+            [Equipment::Rope, Equipment::Canoe, Equipment::Raft].iter().any(|equipment| explorer.has_equipment(equipment))
+        },
+        // embarking or following the river
+        _ => true
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use tokens::*;
+use tokens::Equipment::*;
+
+#[derive(Debug, Clone)]
+pub struct Recipe {
+    pub id: RecipeId,
+    pub name: String,
+    // consumed by crafting
+    pub inputs: Vec<Equipment>,
+    // needed but kept by the explorer
+    pub tools: Vec<Equipment>,
+    pub explorer_trait: Option<ExplorerTrait>,
+    pub region: Option<RegionRequirement>,
+    pub output: Craft
+}
+
+#[derive(Debug, Clone)]
+pub enum RegionRequirement {
+    Terrain(Terrain),
+    Particularity(Particularity)
+}
+
+#[derive(Debug, Clone)]
+pub enum Craft {
+    Equipment(Equipment),
+    Particularity(Particularity),
+    Item(ExplorerItem)
+}
+
+impl Recipe {
+    pub fn craftable(&self, explorer: &Explorer, region: &Region) -> Result<(), String> {
+        if let Some(ref explorer_trait) = self.explorer_trait {
+            if !explorer.has_trait(explorer_trait) {
+                return Err(format!("{} needs a {}.", self.name, explorer_trait));
+            }
+        }
+
+        match self.region {
+            Some(RegionRequirement::Terrain(ref terrain)) if region.terrain != *terrain => {
+                return Err(format!("{} can only be crafted at {:?} regions.", self.name, terrain));
+            },
+            Some(RegionRequirement::Particularity(ref particularity)) if !region.particularities.iter().any(|present| present == particularity) => {
+                return Err(format!("{} needs a {:?} nearby.", self.name, particularity));
+            },
+            _ => { }
+        }
+
+        for equipment in self.inputs.iter().chain(self.tools.iter()) {
+            if !explorer.has_equipment(equipment) {
+                return Err(format!("{} needs {:?}.", self.name, equipment));
+            }
+        }
+
+        Ok(())
+    }
+
+    // consumes the inputs from the explorer and places the output in the inventory or the region.
+    pub fn craft(&self, explorer: &mut Explorer, region: &mut Region) -> Result<(), String> {
+        self.craftable(explorer, region)?;
+
+        for equipment in self.inputs.iter() {
+            explorer.take_equipment(equipment);
+        }
+
+        match self.output {
+            Craft::Equipment(equipment) => explorer.add_equipment(equipment),
+            Craft::Particularity(particularity) => if !region.has_particularity(particularity) {
+                region.particularities.insert(particularity)
+            },
+            Craft::Item(ref item) => explorer.inventory.insert(item.clone())
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Recipe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inputs: Vec<String> = self.inputs.iter().map(|equipment| format!("{:?}", equipment)).collect();
+        write!(f, "{} ({})", self.name, inputs.join(" + "))?;
+        if !self.tools.is_empty() {
+            let tools: Vec<String> = self.tools.iter().map(|equipment| format!("{:?}", equipment)).collect();
+            write!(f, " with {}", tools.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn recipes() -> Vec<Recipe> {
+    vec![
+        Recipe {
+            id: 0,
+            name: "Fire".to_string(),
+            inputs: vec![Tinder, Firewood(1)],
+            tools: vec![Flint],
+            explorer_trait: None,
+            region: None,
+            output: Craft::Particularity(Particularity::Campfire)
+        },
+        Recipe {
+            id: 1,
+            name: "Sealed message".to_string(),
+            inputs: vec![Parchment(1), Wax],
+            tools: vec![SealStamp],
+            explorer_trait: None,
+            region: None,
+            output: Craft::Item(ExplorerItem::Message("a sealed message".to_string()))
+        },
+        Recipe {
+            id: 2,
+            name: "Raft".to_string(),
+            inputs: vec![Firewood(3)],
+            tools: vec![Tools],
+            explorer_trait: None,
+            region: Some(RegionRequirement::Terrain(Terrain::Coast)),
+            output: Craft::Equipment(Raft)
+        },
+        Recipe {
+            id: 3,
+            name: "Arrows".to_string(),
+            inputs: vec![Firewood(1)],
+            tools: vec![Knife],
+            explorer_trait: Some(ExplorerTrait::Ranger),
+            region: None,
+            output: Craft::Equipment(Arrows(10))
+        },
+        Recipe {
+            id: 4,
+            name: "Stew".to_string(),
+            inputs: vec![Herbs(2), Food(1)],
+            tools: vec![Pots],
+            explorer_trait: None,
+            region: Some(RegionRequirement::Particularity(Particularity::Campfire)),
+            output: Craft::Equipment(Food(3))
+        }
+    ]
+}
+
+pub fn recipe(id: RecipeId) -> Option<Recipe> {
+    recipes().into_iter().find(|recipe| recipe.id == id)
+}
+
+// recipes the explorer could craft right now in the given region.
+pub fn available(explorer: &Explorer, region: &Region) -> Vec<Recipe> {
+    recipes().into_iter().filter(|recipe| recipe.craftable(explorer, region).is_ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixtures::*;
+
+    #[test]
+    fn a_second_fire_keeps_the_one_campfire() {
+        let fire = recipes().into_iter().find(|recipe| recipe.name == "Fire").unwrap();
+        let mut explorer = explorer(1, vec![], Some(1), vec![Tinder, Tinder, Firewood(2), Flint]);
+        let mut region = region(1, Terrain::Forest, vec![], vec![]);
+
+        fire.craft(&mut explorer, &mut region).unwrap();
+        fire.craft(&mut explorer, &mut region).unwrap();
+
+        assert_eq!(region.particularities.storage(), &vec![Particularity::Campfire]);
+        assert!(!explorer.has_equipment(&Tinder));
+    }
+}
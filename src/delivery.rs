@@ -0,0 +1,51 @@
+use tokens::*;
+
+// deliveries the explorer carries
+pub fn carried(explorer: &Explorer) -> Vec<Delivery> {
+    explorer.inventory.iter().filter_map(|item| match item {
+        ExplorerItem::Delivery(delivery) => Some(delivery.clone()),
+        _ => None
+    }).collect()
+}
+
+// the region a delivery has to be brought to, characters stay where they are
+pub fn destination(delivery: &Delivery, characters: &[Character]) -> Option<RegionId> {
+    match delivery.destination {
+        Destination::Region(region) => Some(region),
+        Destination::Character(character, _) => characters.get(character).map(|character| character.region)
+    }
+}
+
+pub fn deliverable(explorer: &Explorer, delivery: &Delivery, characters: &[Character]) -> Result<(), String> {
+    if !explorer.inventory.iter().any(|item| *item == ExplorerItem::Delivery(delivery.clone())) {
+        return Err("the explorer does not carry the delivery.".to_string());
+    }
+    match (explorer.region, destination(delivery, characters)) {
+        (Some(region), Some(destination)) if region == destination => Ok(()),
+        (_, Some(destination)) => Err(format!("the delivery goes to region {}.", destination)),
+        (_, None) => Err("the destination is gone.".to_string())
+    }
+}
+
+// hands over the delivery and collects the reward, pleasing client and recipient alike.
+pub fn deliver(explorer: &mut Explorer, delivery: &Delivery, characters: &mut [Character]) -> Result<(), String> {
+    deliverable(explorer, delivery, characters)?;
+
+    if let Some(position) = explorer.inventory.iter().position(|item| *item == ExplorerItem::Delivery(delivery.clone())) {
+        explorer.inventory.storage_mut().remove(position);
+    }
+    for equipment in &delivery.reward {
+        explorer.add_equipment(*equipment);
+    }
+
+    if let Some(client) = characters.get_mut(delivery.client) {
+        client.affection += 1;
+    }
+    if let Destination::Character(recipient, _) = delivery.destination {
+        if let Some(recipient) = characters.get_mut(recipient) {
+            recipient.affection += 1;
+        }
+    }
+
+    Ok(())
+}
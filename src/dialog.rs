@@ -0,0 +1,98 @@
+use tokens::*;
+
+use escort;
+
+pub fn node(character: &Character, id: DialogNodeId) -> Option<&DialogNode> {
+    character.dialog.iter().find(|node| node.id == id)
+}
+
+// the node the explorer is at in a conversation with the character
+pub fn current(character: &Character, explorer: ExplorerId) -> Option<&DialogNode> {
+    match character.conversation {
+        Some((talking, id)) if talking == explorer => node(character, id),
+        _ => None
+    }
+}
+
+pub fn met(condition: &DialogCondition, explorer: &Explorer, character: &Character, completed: &[RealmObjective]) -> bool {
+    match condition {
+        DialogCondition::Carries(equipment) => explorer.has_equipment(equipment),
+        DialogCondition::Completed(objective) => completed.contains(objective),
+        DialogCondition::AffectionAtLeast(affection) => character.affection >= *affection,
+        DialogCondition::AffectionBelow(affection) => character.affection < *affection,
+        DialogCondition::HasErrand => !character.errands.is_empty()
+    }
+}
+
+// indices of the choices of the current node the explorer may pick
+pub fn choices(character: &Character, explorer: &Explorer, completed: &[RealmObjective]) -> Vec<usize> {
+    match current(character, explorer.id) {
+        Some(node) => node.choices.iter().enumerate()
+            .filter(|(_, choice)| choice.conditions.iter().all(|condition| met(condition, explorer, character, completed)))
+            .map(|(index, _)| index)
+            .collect(),
+        None => vec![]
+    }
+}
+
+// characters an explorer can talk to where it stands
+pub fn present(explorer: &Explorer, characters: &[Character]) -> Vec<CharacterId> {
+    characters.iter()
+        .filter(|character| explorer.region == Some(character.region))
+        .map(|character| character.id)
+        .collect()
+}
+
+// starts the conversation over, whoever the character was talking to before.
+pub fn talk(character: &mut Character, explorer: &Explorer) -> Result<(), String> {
+    if explorer.region != Some(character.region) {
+        return Err(format!("{} is not around.", character.name));
+    }
+    if node(character, 0).is_none() {
+        return Err(format!("{} has nothing to say.", character.name));
+    }
+    character.conversation = Some((explorer.id, 0));
+    Ok(())
+}
+
+pub fn answer(character: &mut Character, explorer: &mut Explorer, choice: usize, completed: &[RealmObjective], story: &mut String, escorts: &mut Vec<Escort>) -> Result<(), String> {
+    if explorer.region != Some(character.region) {
+        return Err(format!("{} is not around.", character.name));
+    }
+    if !choices(character, explorer, completed).contains(&choice) {
+        return Err("that is not an answer to give.".to_string());
+    }
+
+    let choice = match current(character, explorer.id) {
+        Some(node) => node.choices[choice].clone(),
+        None => return Err(format!("{} is not talking to the explorer.", character.name))
+    };
+
+    // an answer is given whole or not at all
+    if choice.effects.iter().any(|effect| matches!(effect, DialogEffect::Escort(_))) {
+        escort::startable(escorts, character, explorer)?;
+    }
+
+    for effect in &choice.effects {
+        match effect {
+            DialogEffect::GiveItem(ExplorerItem::Equipment(equipment)) => explorer.add_equipment(*equipment),
+            DialogEffect::GiveItem(item) => explorer.inventory.insert(item.clone()),
+            DialogEffect::TakeEquipment(equipment) => {
+                explorer.take_equipment(equipment);
+            },
+            DialogEffect::Affection(affection) => character.affection += affection,
+            DialogEffect::Story(text) => *story = text.clone(),
+            DialogEffect::Errand => {
+                if !character.errands.is_empty() {
+                    explorer.inventory.insert(ExplorerItem::Delivery(character.errands.remove(0)));
+                }
+            },
+            DialogEffect::Escort(destination) => {
+                escort::start(escorts, character, explorer, *destination)?;
+            }
+        }
+    }
+
+    character.conversation = choice.next.map(|next| (explorer.id, next));
+    Ok(())
+}
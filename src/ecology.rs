@@ -0,0 +1,142 @@
+use tokens::*;
+use utility::*;
+
+// realm ages a season lasts
+pub const SEASON_LENGTH: usize = 10;
+// growth it takes for one resource to come back
+const GROWTH: usize = 10;
+
+pub fn season(age: usize) -> Season {
+    match (age / SEASON_LENGTH) % 4 {
+        0 => Season::Spring,
+        1 => Season::Summer,
+        2 => Season::Autumn,
+        _ => Season::Winter
+    }
+}
+
+// the most resources a region holds
+pub fn capacity(region: &Region) -> usize {
+    let terrain = match region.terrain {
+        Terrain::Planes => 6,
+        Terrain::Forest => 5,
+        Terrain::Coast => 3,
+        Terrain::Mountain => 2
+    };
+    let rich = [Particularity::Grove, Particularity::Lake, Particularity::Grasland].iter()
+        .filter(|particularity| region.has_particularity(**particularity))
+        .count();
+    terrain + 2 * rich
+}
+
+// growth per realm age, in percent of what the terrain yields in summer
+fn seasonal(season: Season) -> usize {
+    match season {
+        Season::Spring => 150,
+        Season::Summer => 100,
+        Season::Autumn => 50,
+        Season::Winter => 0
+    }
+}
+
+pub fn regeneration(region: &Region, season: Season) -> usize {
+    let terrain = match region.terrain {
+        Terrain::Planes | Terrain::Forest => 3,
+        Terrain::Coast => 2,
+        Terrain::Mountain => 1
+    };
+    let rich = [Particularity::Grove, Particularity::Lake, Particularity::Grasland].iter()
+        .filter(|particularity| region.has_particularity(**particularity))
+        .count();
+    ((terrain + 2 * rich) * seasonal(season) / 100).saturating_sub(region.depletion)
+}
+
+// taking from a region that is running low wears it out
+pub fn exploit(region: &mut Region) {
+    if region.resources * 3 <= capacity(region) {
+        region.depletion += 1;
+    }
+}
+
+// lets resources grow back for one realm age, a region left alone until full recovers from overhunting.
+pub fn regenerate(regions: &mut SelectionHashMap<Region>, age: usize) {
+    let season = season(age);
+    for (_, region) in regions.iter_mut() {
+        let capacity = capacity(region);
+        if region.resources >= capacity {
+            region.growth = 0;
+            region.depletion = region.depletion.saturating_sub(1);
+            continue;
+        }
+
+        region.growth += regeneration(region, season);
+        while region.growth >= GROWTH && region.resources < capacity {
+            region.growth -= GROWTH;
+            region.resources += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixtures::*;
+
+    fn planes(resources: usize, depletion: usize) -> SelectionHashMap<Region> {
+        let mut planes = region(1, Terrain::Planes, vec![], vec![]);
+        planes.resources = resources;
+        planes.depletion = depletion;
+        regions(vec![planes])
+    }
+
+    #[test]
+    fn resources_grow_back_with_the_season() {
+        // planes grow 4 a realm age in spring, nothing in winter
+        let mut regions = planes(0, 0);
+        for age in 0..5 {
+            regenerate(&mut regions, age);
+        }
+        assert_eq!(regions.storage()[&1].resources, 2);
+
+        let mut regions = planes(0, 0);
+        for age in 3 * SEASON_LENGTH..4 * SEASON_LENGTH {
+            regenerate(&mut regions, age);
+        }
+        assert_eq!(regions.storage()[&1].resources, 0);
+    }
+
+    #[test]
+    fn resources_do_not_grow_past_the_capacity() {
+        let mut regions = planes(5, 0);
+        for age in 0..10 {
+            regenerate(&mut regions, age);
+        }
+        assert_eq!(regions.storage()[&1].resources, capacity(&regions.storage()[&1]));
+    }
+
+    #[test]
+    fn taking_from_a_region_running_low_wears_it_out() {
+        let mut regions = planes(3, 0);
+        exploit(regions.storage_mut().get_mut(&1).unwrap());
+        assert_eq!(regions.storage()[&1].depletion, 0);
+
+        let mut regions = planes(2, 0);
+        exploit(regions.storage_mut().get_mut(&1).unwrap());
+        assert_eq!(regions.storage()[&1].depletion, 1);
+    }
+
+    #[test]
+    fn a_worn_out_region_recovers_once_left_alone_until_full() {
+        // depleted by 3 the planes grow only 1 a realm age in spring
+        let mut regions = planes(5, 3);
+        for age in 0..10 {
+            regenerate(&mut regions, age);
+        }
+        assert_eq!(regions.storage()[&1].resources, 6);
+        assert_eq!(regions.storage()[&1].depletion, 3);
+
+        regenerate(&mut regions, 10);
+        regenerate(&mut regions, 11);
+        assert_eq!(regions.storage()[&1].depletion, 1);
+    }
+}
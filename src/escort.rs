@@ -0,0 +1,126 @@
+use rand::{thread_rng, distributions::Uniform, Rng};
+
+use tokens::*;
+use utility::*;
+use cartography;
+
+// percent chance of trouble on the way into a region
+fn danger(region: &Region) -> usize {
+    let danger = match region.terrain {
+        Terrain::Mountain => 35,
+        Terrain::Forest => 25,
+        Terrain::Coast => 15,
+        Terrain::Planes => 10
+    };
+    if region.has_particularity(Particularity::River) {
+        danger + 10
+    } else {
+        danger
+    }
+}
+
+fn trouble(region: &Region) -> &'static str {
+    match region.terrain {
+        Terrain::Mountain => "a rockslide",
+        Terrain::Forest => "thick undergrowth",
+        Terrain::Coast => "the rising tide",
+        Terrain::Planes => "a sudden fog"
+    }
+}
+
+// knowing the way makes it safer
+pub fn chance(explorer: &Explorer, region: &Region, surveys: &[Survey]) -> usize {
+    let mut known = 0;
+    if explorer.has_equipment(&Equipment::Compass) {
+        known += 10;
+    }
+    if region.mapped.is_some() || surveys.iter().any(|survey| survey.region == region.id) {
+        known += 10;
+    }
+    if explorer.has_trait(&ExplorerTrait::Ranger) {
+        known += 5;
+    }
+    danger(region).saturating_sub(known)
+}
+
+pub fn startable(escorts: &[Escort], character: &Character, explorer: &Explorer) -> Result<(), String> {
+    if explorer.region != Some(character.region) {
+        return Err(format!("{} is not around.", character.name));
+    }
+    if escorts.iter().any(|escort| escort.character == character.id && escort.state != EscortState::SafeAndSound) {
+        return Err(format!("{} is already being escorted.", character.name));
+    }
+    Ok(())
+}
+
+pub fn start(escorts: &mut Vec<Escort>, character: &Character, explorer: &Explorer, destination: RegionId) -> Result<(), String> {
+    startable(escorts, character, explorer)?;
+
+    escorts.push(Escort {
+        character: character.id,
+        explorer: explorer.id,
+        destination,
+        state: EscortState::Underway,
+        log: vec![format!("{} sets out for region {}.", character.name, destination)]
+    });
+    Ok(())
+}
+
+// the escorted characters try to keep up with the explorer after it changed region.
+pub fn follow(realm: &mut Realm, regions: &SelectionHashMap<Region>, explorer: ExplorerId) {
+    let surveys = cartography::carried_surveys(&realm.expedition.explorers);
+    let explorer = match realm.expedition.explorers.storage().get(explorer) {
+        Some(explorer) => explorer,
+        None => return
+    };
+    let to = match explorer.region.and_then(|region| regions.storage().get(&region)) {
+        Some(region) => region,
+        None => return
+    };
+
+    let mut rng = thread_rng();
+    for escort in realm.escorts.iter_mut().filter(|escort| escort.explorer == explorer.id && escort.state != EscortState::SafeAndSound) {
+        let character = match realm.characters.get_mut(escort.character) {
+            Some(character) => character,
+            None => continue
+        };
+
+        match escort.state {
+            EscortState::Disoriented => {
+                if character.region == to.id {
+                    escort.state = EscortState::Underway;
+                    escort.log.push(format!("found {} again in region {}.", character.name, to.id));
+                }
+            },
+            EscortState::Underway => {
+                let from = match regions.storage().get(&character.region) {
+                    Some(region) => region,
+                    None => continue
+                };
+
+                if !from.neighbors.contains(&to.id) {
+                    escort.state = EscortState::Disoriented;
+                    escort.log.push(format!("{} could not keep up and is lost in region {}.", character.name, from.id));
+                } else if rng.sample(Uniform::new(0, 100)) < chance(explorer, to, &surveys) {
+                    // the character strays into a region around the one the explorer went to
+                    let astray: Vec<RegionId> = to.neighbors.iter().filter(|neighbor| regions.storage().contains_key(neighbor)).cloned().collect();
+                    character.region = match astray.len() {
+                        0 => from.id,
+                        len => astray[rng.sample(Uniform::new(0, len))]
+                    };
+                    escort.state = EscortState::Disoriented;
+                    escort.log.push(format!("{} got disoriented by {} and wandered into region {}.", character.name, trouble(to), character.region));
+                } else {
+                    character.region = to.id;
+                }
+            },
+            EscortState::SafeAndSound => { }
+        }
+
+        if character.region == escort.destination {
+            escort.state = EscortState::SafeAndSound;
+            escort.log.push(format!("{} arrived safe and sound.", character.name));
+            character.affection += 2;
+        }
+    }
+}
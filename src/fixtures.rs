@@ -0,0 +1,42 @@
+// hand-built regions and explorers for the unit tests
+use tokens::*;
+use utility::*;
+
+pub fn region(id: RegionId, terrain: Terrain, particularities: Vec<Particularity>, neighbors: Vec<RegionId>) -> Region {
+    Region {
+        id,
+        terrain,
+        particularities: SelectionStorage::new_from(&particularities),
+        buildings: SelectionStorage::new(),
+        mapped: None,
+        resources: 0,
+        sight: RegionVisibility::Complete,
+        seen: None,
+        owner: None,
+        blockade: None,
+        modifications: vec![],
+        growth: 0,
+        depletion: 0,
+        weather: Weather::Clear,
+        neighbors,
+        hex_offset_coords: (0, 0)
+    }
+}
+
+pub fn regions(regions: Vec<Region>) -> SelectionHashMap<Region> {
+    let mut map = SelectionHashMap::new();
+    for region in regions {
+        map.insert(region.id, region);
+    }
+    map
+}
+
+pub fn explorer(id: ExplorerId, traits: Vec<ExplorerTrait>, region: Option<RegionId>, equipment: Vec<Equipment>) -> Explorer {
+    Explorer {
+        id,
+        traits: SelectionStorage::new_from(&traits),
+        region,
+        inventory: SelectionStorage::new_from(&equipment.into_iter().map(ExplorerItem::Equipment).collect()),
+        trail: vec![]
+    }
+}
@@ -0,0 +1,118 @@
+use tokens::*;
+use utility::*;
+
+// particularities a ranger reads from the land around
+const NATURE: [Particularity; 7] = [Particularity::River, Particularity::Creek, Particularity::Lake, Particularity::Pond, Particularity::Canyon, Particularity::Grove, Particularity::Clearing];
+// particularities standing out enough to be made out through a telescope
+const LANDMARKS: [Particularity; 7] = [Particularity::Town, Particularity::Castle, Particularity::Fortress, Particularity::Lighthouse, Particularity::Cliffs, Particularity::Ship, Particularity::Island];
+// particularities people will talk about when paid
+const HEARSAY: [Particularity; 4] = [Particularity::Town, Particularity::Merchant, Particularity::Carravan, Particularity::Farmers];
+
+fn carries(explorer: &Explorer, item: &ExplorerItem) -> bool {
+    explorer.inventory.iter().any(|carried| carried == item)
+}
+
+// particularities of the surroundings the explorer picks up on, depending on trait and equipment.
+fn leads(explorer: &Explorer) -> Vec<Particularity> {
+    let mut leads = vec![];
+    if explorer.has_trait(&ExplorerTrait::Ranger) {
+        leads.extend(NATURE.iter());
+    }
+    if explorer.has_equipment(&Equipment::Telescope) {
+        leads.extend(LANDMARKS.iter());
+    }
+    if explorer.has_equipment(&Equipment::Coins(1)) {
+        leads.extend(HEARSAY.iter());
+    }
+    leads
+}
+
+// the clues investigating a particularity of the region yields, none of which the explorer carries yet.
+pub fn clues(explorer: &Explorer, region: &Region, particularity: Particularity, regions: &SelectionHashMap<Region>) -> Result<Vec<ExplorerItem>, String> {
+    if !region.has_particularity(particularity) {
+        return Err(format!("there is no {:?} here.", particularity));
+    }
+
+    let mut clues = vec![ExplorerItem::Particularity(region.id, particularity)];
+
+    let leads = leads(explorer);
+    for neighbor in region.neighbors.iter().filter_map(|neighbor| regions.storage().get(neighbor)) {
+        for lead in neighbor.particularities.iter().filter(|particularity| leads.contains(particularity)) {
+            clues.push(ExplorerItem::Particularity(neighbor.id, *lead));
+        }
+    }
+
+    clues.retain(|clue| !carries(explorer, clue));
+    Ok(clues)
+}
+
+// findings all clues are collected for
+pub fn combinations(explorer: &Explorer) -> Vec<Finding> {
+    Finding::all().into_iter().filter(|finding| {
+        finding.clues().iter().all(|clue| explorer.inventory.iter().any(|item| match item {
+            ExplorerItem::Particularity(_, particularity) => particularity == clue,
+            _ => false
+        }))
+    }).collect()
+}
+
+// collects the clues and puts together whatever they add up to, using up the clues that went into it.
+pub fn investigate(explorer: &mut Explorer, region: &Region, particularity: Particularity, regions: &SelectionHashMap<Region>) -> Result<Vec<Finding>, String> {
+    for clue in clues(explorer, region, particularity, regions)? {
+        explorer.note_found(&clue);
+        explorer.inventory.insert(clue);
+    }
+
+    let findings = combinations(explorer);
+    for finding in &findings {
+        for clue in finding.clues() {
+            let position = explorer.inventory.iter().position(|item| match item {
+                ExplorerItem::Particularity(_, particularity) => *particularity == clue,
+                _ => false
+            });
+            if let Some(position) = position {
+                explorer.inventory.storage_mut().remove(position);
+            }
+        }
+        explorer.note_found(&ExplorerItem::Finding(*finding));
+        explorer.inventory.insert(ExplorerItem::Finding(*finding));
+    }
+
+    Ok(findings)
+}
+
+// hands a finding to a character that cares about it.
+pub fn report(explorer: &mut Explorer, character: &mut Character, finding: Finding) -> Result<(), String> {
+    if explorer.region != Some(character.region) {
+        return Err(format!("{} is not around.", character.name));
+    }
+    if !character.interests.contains(&finding) {
+        return Err(format!("{} does not care about {}.", character.name, finding));
+    }
+
+    match explorer.inventory.iter().position(|item| *item == ExplorerItem::Finding(finding)) {
+        Some(position) => {
+            explorer.inventory.storage_mut().remove(position);
+        },
+        None => return Err(format!("the explorer has not found {}.", finding))
+    }
+
+    character.interests.retain(|interest| *interest != finding);
+    character.affection += 2;
+    Ok(())
+}
+
+// findings the explorer carries and characters around it want to hear about
+pub fn reports(explorer: &Explorer, characters: &[Character]) -> Vec<(CharacterId, Finding)> {
+    let mut reports = vec![];
+    for character in characters.iter().filter(|character| explorer.region == Some(character.region)) {
+        for item in explorer.inventory.iter() {
+            if let ExplorerItem::Finding(finding) = item {
+                if character.interests.contains(finding) && !reports.contains(&(character.id, *finding)) {
+                    reports.push((character.id, *finding));
+                }
+            }
+        }
+    }
+    reports
+}
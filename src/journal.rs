@@ -0,0 +1,139 @@
+use std::fs::{self, File};
+
+use bincode::{serialize_into, deserialize_from};
+
+use tokens::*;
+use utility::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum JournalKind {
+	Order,
+	Done,
+	Rejected,
+	Objective,
+	Story,
+	Event
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+	pub age: usize,
+	pub kind: JournalKind,
+	pub text: String
+}
+
+// what happened in a realm as the client saw it, kept in a file per client and realm
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Journal {
+	pub client: ClientId,
+	pub realm: RealmId,
+	// realm age of the last realm view, orders are written down with it
+	pub age: usize,
+	pub entries: SelectionStorage<JournalEntry>
+}
+
+impl Journal {
+	fn path(client: ClientId, realm: RealmId) -> String {
+		format!("{}.{}.journal", client, realm)
+	}
+
+	// a journal not written before starts out empty
+	pub fn load(client: ClientId, realm: &Realm) -> Journal {
+		let journal = File::open(Journal::path(client, realm.id)).ok()
+			.and_then(|mut file| deserialize_from(&mut file).ok());
+		let mut journal = journal.unwrap_or_else(|| Journal {
+			client,
+			realm: realm.id,
+			age: realm.age,
+			entries: SelectionStorage::new()
+		});
+		journal.entries.last();
+		journal
+	}
+
+	pub fn delete(client: ClientId, realm: RealmId) {
+		let _ = fs::remove_file(Journal::path(client, realm));
+	}
+
+	// every journal of the client, for a client whose realms are gone
+	pub fn clear(client: ClientId) {
+		let prefix = format!("{}.", client);
+		if let Ok(entries) = fs::read_dir(".") {
+			for entry in entries.filter_map(|entry| entry.ok()) {
+				let name = entry.file_name().to_string_lossy().into_owned();
+				if name.starts_with(&prefix) && name.ends_with(".journal") {
+					let _ = fs::remove_file(entry.path());
+				}
+			}
+		}
+	}
+
+	pub fn save(&self) {
+		if let Ok(mut file) = File::create(Journal::path(self.client, self.realm)) {
+			let _ = serialize_into(&mut file, self);
+		}
+	}
+
+	fn write(&mut self, kind: JournalKind, text: String) {
+		self.entries.insert(JournalEntry { age: self.age, kind, text });
+		self.entries.last();
+	}
+
+	// writes down an order and what the server made of it
+	pub fn order(&mut self, request: &RealmsProtocol, outcome: &Result<Realm, String>) {
+		self.write(JournalKind::Order, describe(request));
+		match outcome {
+			Ok(_) => self.write(JournalKind::Done, "done.".to_string()),
+			Err(reason) => self.write(JournalKind::Rejected, reason.clone())
+		}
+		self.save();
+	}
+
+	// writes down what changed between two views of the realm
+	pub fn changes(&mut self, before: &Realm, after: &Realm) {
+		let written = self.entries.iter().len();
+		self.age = after.age;
+
+		for objective in after.completed.iter().filter(|objective| !before.completed.contains(objective)) {
+			self.write(JournalKind::Objective, format!("completed: {}", objective));
+		}
+		if after.story != before.story {
+			self.write(JournalKind::Story, after.story.clone());
+		}
+		for escort in &after.escorts {
+			let logged = before.escorts.iter()
+				.find(|logged| logged.character == escort.character && logged.explorer == escort.explorer && logged.destination == escort.destination)
+				.map(|logged| logged.log.len())
+				.unwrap_or(0);
+			for line in escort.log.iter().skip(logged) {
+				self.write(JournalKind::Event, line.clone());
+			}
+		}
+		if after.done && !before.done {
+			self.write(JournalKind::Event, "the realm is done.".to_string());
+		}
+
+		if self.entries.iter().len() != written {
+			self.save();
+		}
+	}
+}
+
+fn describe(request: &RealmsProtocol) -> String {
+	match request {
+		RealmsProtocol::Explorer(Move::ChangeRegion(_, region, explorer)) => format!("explorer {} heads for region {}", explorer, region),
+		RealmsProtocol::Explorer(Move::Follow(_, explorer, tracked)) => format!("explorer {} follows the tracks of explorer {}", explorer, tracked),
+		RealmsProtocol::Explorer(Move::Action(_, _, explorer, action)) => format!("explorer {}: {}", explorer, action),
+		RealmsProtocol::DropEquipment(_, _, explorer, equipment) => format!("explorer {} drops {:?}", explorer, equipment),
+		RealmsProtocol::PickEquipment(_, _, explorer, equipment) => format!("explorer {} picks up {:?}", explorer, equipment),
+		RealmsProtocol::InvestigateParticularity(_, region, explorer, particularity) => format!("explorer {} investigates {:?} at {}", explorer, particularity, region),
+		RealmsProtocol::ForgetParticularity(_, region, explorer, particularity) => format!("explorer {} forgets {:?} at {}", explorer, particularity, region),
+		RealmsProtocol::Craft(_, _, explorer, recipe) => format!("explorer {} crafts recipe {}", explorer, recipe),
+		RealmsProtocol::GiveItem(_, explorer, recipient, item) => format!("explorer {} gives {} to explorer {}", explorer, item, recipient),
+		RealmsProtocol::Talk(_, explorer, character) => format!("explorer {} talks to character {}", explorer, character),
+		RealmsProtocol::Answer(_, explorer, character, choice) => format!("explorer {} answers character {} with {}", explorer, character, choice + 1),
+		RealmsProtocol::Report(_, explorer, character, finding) => format!("explorer {} reports {} to character {}", explorer, finding, character),
+		RealmsProtocol::Deliver(_, explorer, delivery) => format!("explorer {} delivers {}", explorer, delivery),
+		request => format!("{}", request)
+	}
+}
@@ -0,0 +1,194 @@
+use std::fs::File;
+use std::io::prelude::*;
+
+use termion::event::Key;
+
+// what the client does on a key press, whichever key it is bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Command {
+	Up,
+	Down,
+	Left,
+	Right,
+	Select,
+	Back,
+	NextTab,
+	Help,
+	Quit,
+	NewRealm,
+	ArchiveRealm,
+	DeleteRealm,
+	DuplicateRealm,
+	Actions,
+	Inventory,
+	Move,
+	Craft,
+	Give,
+	Sort,
+	Filter
+}
+
+impl Command {
+	pub fn all() -> Vec<Command> {
+		vec![Command::Up, Command::Down, Command::Left, Command::Right, Command::Select, Command::Back, Command::NextTab, Command::Help, Command::Quit,
+			Command::NewRealm, Command::ArchiveRealm, Command::DeleteRealm, Command::DuplicateRealm, Command::Actions, Command::Inventory, Command::Move, Command::Craft, Command::Give,
+			Command::Sort, Command::Filter]
+	}
+
+	// the name used in the keys file
+	pub fn name(&self) -> &'static str {
+		match self {
+			Command::Up => "up",
+			Command::Down => "down",
+			Command::Left => "left",
+			Command::Right => "right",
+			Command::Select => "select",
+			Command::Back => "back",
+			Command::NextTab => "next-tab",
+			Command::Help => "help",
+			Command::Quit => "quit",
+			Command::NewRealm => "new-realm",
+			Command::ArchiveRealm => "archive-realm",
+			Command::DeleteRealm => "delete-realm",
+			Command::DuplicateRealm => "duplicate-realm",
+			Command::Actions => "actions",
+			Command::Inventory => "inventory",
+			Command::Move => "move",
+			Command::Craft => "craft",
+			Command::Give => "give",
+			Command::Sort => "sort",
+			Command::Filter => "filter"
+		}
+	}
+
+	fn named(name: &str) -> Option<Command> {
+		Command::all().into_iter().find(|command| command.name() == name)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+	// the first key bound to a command is the one shown in the ui
+	bindings: Vec<(Key, Command)>
+}
+
+impl Keymap {
+	fn new(cursor: [(Key, Command); 4]) -> Keymap {
+		let mut bindings = cursor.to_vec();
+		bindings.extend_from_slice(&[
+			(Key::Char('\n'), Command::Select),
+			(Key::Backspace, Command::Back),
+			(Key::Char('\t'), Command::NextTab),
+			(Key::Char('?'), Command::Help),
+			(Key::Char('q'), Command::Quit),
+			(Key::Char('r'), Command::NewRealm),
+			(Key::Char('z'), Command::ArchiveRealm),
+			(Key::Char('x'), Command::DeleteRealm),
+			(Key::Char('d'), Command::DuplicateRealm),
+			(Key::Char('a'), Command::Actions),
+			(Key::Char('i'), Command::Inventory),
+			(Key::Char('m'), Command::Move),
+			(Key::Char('c'), Command::Craft),
+			(Key::Char('g'), Command::Give),
+			(Key::Char('s'), Command::Sort),
+			(Key::Char('f'), Command::Filter)
+		]);
+		Keymap { bindings }
+	}
+
+	pub fn arrows() -> Keymap {
+		Keymap::new([(Key::Up, Command::Up), (Key::Down, Command::Down), (Key::Left, Command::Left), (Key::Right, Command::Right)])
+	}
+
+	pub fn vim() -> Keymap {
+		Keymap::new([(Key::Char('k'), Command::Up), (Key::Char('j'), Command::Down), (Key::Char('h'), Command::Left), (Key::Char('l'), Command::Right)])
+	}
+
+	// reads a keys file, a preset line first and then a line per binding, each replacing the preset keys of its command:
+	// preset vim
+	// quit Q
+	pub fn load(path: &str) -> Result<Keymap, String> {
+		let mut contents = String::new();
+		match File::open(path) {
+			Ok(mut file) => {
+				file.read_to_string(&mut contents).map_err(|error| format!("could not read {}: {}", path, error))?;
+			},
+			// no keys file is no mistake
+			Err(_) => return Ok(Keymap::arrows())
+		}
+
+		let mut keymap = Keymap::arrows();
+		let mut rebound: Vec<Command> = vec![];
+		for (number, line) in contents.lines().enumerate() {
+			let words: Vec<&str> = line.split_whitespace().collect();
+			match words.as_slice() {
+				[] => { },
+				[comment, ..] if comment.starts_with('#') => { },
+				["preset", "vim"] => keymap = Keymap::vim(),
+				["preset", "arrows"] => keymap = Keymap::arrows(),
+				[name, key] => {
+					let command = Command::named(name).ok_or_else(|| format!("{} line {}: there is no command {}.", path, number + 1, name))?;
+					let key = parse(key).ok_or_else(|| format!("{} line {}: there is no key {}.", path, number + 1, key))?;
+					if !rebound.contains(&command) {
+						keymap.bindings.retain(|(_, bound)| *bound != command);
+						rebound.push(command);
+					}
+					keymap.bindings.retain(|(bound, _)| *bound != key);
+					keymap.bindings.push((key, command));
+				},
+				_ => return Err(format!("{} line {}: expected a command and a key.", path, number + 1))
+			}
+		}
+
+		Ok(keymap)
+	}
+
+	pub fn command(&self, key: Key) -> Option<Command> {
+		self.bindings.iter().find(|(bound, _)| *bound == key).map(|(_, command)| *command)
+	}
+
+	pub fn key(&self, command: Command) -> String {
+		match self.bindings.iter().find(|(_, bound)| *bound == command) {
+			Some((key, _)) => label(*key),
+			None => "-".to_string()
+		}
+	}
+}
+
+fn parse(key: &str) -> Option<Key> {
+	match key {
+		"Up" => Some(Key::Up),
+		"Down" => Some(Key::Down),
+		"Left" => Some(Key::Left),
+		"Right" => Some(Key::Right),
+		"Enter" => Some(Key::Char('\n')),
+		"Tab" => Some(Key::Char('\t')),
+		"Space" => Some(Key::Char(' ')),
+		"Backspace" => Some(Key::Backspace),
+		"Esc" => Some(Key::Esc),
+		_ => {
+			let mut chars = key.chars();
+			match (chars.next(), chars.next()) {
+				(Some(character), None) => Some(Key::Char(character)),
+				_ => None
+			}
+		}
+	}
+}
+
+// how a key is written in panel titles
+pub fn label(key: Key) -> String {
+	match key {
+		Key::Up => "↑".to_string(),
+		Key::Down => "↓".to_string(),
+		Key::Left => "←".to_string(),
+		Key::Right => "→".to_string(),
+		Key::Char('\n') => "Enter".to_string(),
+		Key::Char('\t') => "Tab".to_string(),
+		Key::Char(' ') => "Space".to_string(),
+		Key::Backspace => "Bsp".to_string(),
+		Key::Esc => "Esc".to_string(),
+		Key::Char(character) => character.to_string(),
+		key => format!("{:?}", key)
+	}
+}
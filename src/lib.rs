@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate serde_derive;
+extern crate serde;
+#[cfg(feature = "cli")]
+extern crate serde_json;
+
+extern crate bincode;
+
+extern crate rand;
+
+#[cfg(feature = "cli")]
+extern crate tui;
+#[cfg(feature = "cli")]
+extern crate termion;
+
+extern crate chrono;
+
+extern crate itertools;
+
+extern crate uuid;
+
+#[cfg(feature = "cli")]
+use termion::event;
+
+#[cfg(feature = "cli")]
+pub mod client;
+#[cfg(feature = "cli")]
+pub mod client_dashboard;
+pub mod server;
+#[cfg(feature = "cli")]
+pub mod server_dashboard;
+pub mod tokens;
+pub mod utility;
+pub mod realms;
+pub mod hex;
+pub mod crafting;
+pub mod construction;
+pub mod actions;
+pub mod sailing;
+pub mod cartography;
+pub mod tracking;
+pub mod dialog;
+pub mod investigation;
+pub mod delivery;
+pub mod escort;
+pub mod claims;
+pub mod terraforming;
+pub mod ecology;
+pub mod weather;
+#[cfg(feature = "cli")]
+pub mod keymap;
+pub mod journal;
+pub mod browser;
+#[cfg(feature = "cli")]
+pub mod script;
+pub mod client_api;
+pub mod bots;
+pub mod transport;
+#[cfg(test)]
+mod fixtures;
+
+#[cfg(feature = "cli")]
+pub enum Event {
+    Input(event::Key),
+    Tick,
+}
@@ -1,48 +1,28 @@
-#[macro_use]
-extern crate serde_derive;
-extern crate serde;
-
-extern crate bincode;
-
-extern crate rand;
-
-extern crate tui;
 extern crate termion;
 
-extern crate chrono;
-
-extern crate itertools;
-
-extern crate uuid;
+extern crate realms;
 
 use std::env;
 use std::io;
+use std::io::BufReader;
+use std::fs::File;
+use std::process;
 use std::sync::mpsc;
 use std::thread;
 use std::time;
-use std::net::TcpStream;
 
-use termion::event;
 use termion::input::TermRead;
 
-mod client;
-mod client_dashboard;
-mod server;
-mod server_dashboard;
-mod tokens;
-mod utility;
-mod realms;
-mod hex;
+use realms::{client, server, script, keymap, bots, Event};
+use realms::client_api::RealmsClient;
 
 #[derive(Debug)]
 pub enum Mode {
     Server,
-    Client
-}
-
-pub enum Event {
-    Input(event::Key),
-    Tick,
+    Client,
+    Play,
+    Script,
+    Bot
 }
 
 fn main() {
@@ -56,44 +36,150 @@ fn main() {
 	if args.len() >= 2 && &args[1] == "client" {
     	mode = Mode::Client;
 	}
+	// realms play, the game runs in the client without a server
+	if args.len() >= 2 && &args[1] == "play" {
+    	mode = Mode::Play;
+	}
 	if args.len() >= 3 {
     	host = &args[2];
 	}
+	// realms script [file] [host], the commands are read from stdin without a file or with -
+	let mut script = "-";
+	if args.len() >= 2 && &args[1] == "script" {
+    	mode = Mode::Script;
+    	host = "127.0.0.1:8080";
+    	if args.len() >= 3 {
+    		script = &args[2];
+    	}
+    	if args.len() >= 4 {
+    		host = &args[3];
+    	}
+	}
+
+	// realms bot <n> [host], n bots play a realm each against the server at the same time
+	let mut bot_count = 1;
+	if args.len() >= 2 && &args[1] == "bot" {
+    	mode = Mode::Bot;
+    	host = "127.0.0.1:8080";
+    	if args.len() >= 3 {
+    		bot_count = args[2].parse().unwrap_or_else(|_| {
+    			eprintln!("{} is not a number of bots.", args[2]);
+    			process::exit(1);
+    		});
+    	}
+    	if args.len() >= 4 {
+    		host = &args[3];
+    	}
+	}
 
 	match mode {
 	    Mode::Client => {
-
-		    let (tx, rx) = mpsc::channel();
-			let input_tx = tx.clone();
-
-			// event loop
-			thread::spawn(move || {
-		        let stdin = io::stdin();
-		        for c in stdin.keys() {
-		            let evt = c.unwrap();
-		            input_tx.send(Event::Input(evt)).unwrap();
-		            if evt == event::Key::Char('q') {
-		                break;
-		            }
-		        }
-		    });
-
-			// tick loop
-		    thread::spawn(move || {
-		        let tx = tx.clone();
-		        loop {
-		            tx.send(Event::Tick).unwrap();
-		            thread::sleep(time::Duration::from_millis(500));
-		        }
-			});
-
-	    	if let Ok(stream) = TcpStream::connect(host) {
-	    		let periscope = client::Periscope::new(stream);
-		 		periscope.run(&rx).expect("io error");
-			}
+	    	match RealmsClient::open(host) {
+	    		Ok(realms_client) => play(realms_client, false),
+	    		Err(error) => {
+	    			eprintln!("{}", error);
+	    			process::exit(1);
+	    		}
+	    	}
+	    },
+	    Mode::Play => {
+	    	play(RealmsClient::offline(), true);
 	    },
 	    Mode::Server => {
 	    	server::run(host.to_string());
+	    },
+	    Mode::Script => {
+	    	let result = RealmsClient::open(host)
+	    		.and_then(|realms_client| {
+	    			let scripted = script::Script::new(realms_client);
+	    			if script == "-" {
+	    				let stdin = io::stdin();
+	    				scripted.run(stdin.lock())
+	    			} else {
+	    				let file = File::open(script).map_err(|error| format!("could not open {}: {}", script, error))?;
+	    				scripted.run(BufReader::new(file))
+	    			}
+	    		});
+	    	if let Err(error) = result {
+	    		eprintln!("{}", error);
+	    		process::exit(1);
+	    	}
+	    },
+	    Mode::Bot => {
+	    	let started = time::Instant::now();
+	    	let variants = bots::BotVariant::all();
+	    	let players: Vec<_> = (0..bot_count).map(|id| {
+	    		let host = host.to_string();
+	    		let variant = variants[id % variants.len()];
+	    		thread::spawn(move || {
+	    			let mut realms_client = RealmsClient::open(&host)?;
+	    			realms_client.register()?;
+	    			let mut bot = bots::Bot::new(id, variant);
+	    			let outcome = bots::play(&mut realms_client, &mut bot, bots::ORDERS);
+	    			let _ = realms_client.quit();
+	    			outcome
+	    		})
+	    	}).collect();
+
+	    	let mut failed = false;
+	    	let mut orders = 0;
+	    	for (id, player) in players.into_iter().enumerate() {
+	    		match player.join() {
+	    			Ok(Ok(outcome)) => {
+	    				orders += outcome.orders;
+	    				println!("{}", outcome);
+	    			},
+	    			Ok(Err(error)) => {
+	    				failed = true;
+	    				eprintln!("bot {}: {}", id, error);
+	    			},
+	    			Err(_) => {
+	    				failed = true;
+	    				eprintln!("bot {} panicked.", id);
+	    			}
+	    		}
+	    	}
+	    	let elapsed = started.elapsed().as_secs_f64();
+	    	println!("{} orders in {:.2}s, {:.0} orders per second", orders, elapsed, orders as f64 / elapsed.max(0.001));
+	    	if failed {
+	    		process::exit(1);
+	    	}
 	    }
 	}
 }
+
+// the terminal client, whatever it plays against
+fn play(realms_client: RealmsClient, offline: bool) {
+    let keymap = keymap::Keymap::load("client.keys").unwrap_or_else(|error| {
+    	eprintln!("{} falling back to the arrow keys.", error);
+    	keymap::Keymap::arrows()
+    });
+
+    let (tx, rx) = mpsc::channel();
+	let input_tx = tx.clone();
+
+	// event loop
+	let input_keymap = keymap.clone();
+	thread::spawn(move || {
+        let stdin = io::stdin();
+        for c in stdin.keys() {
+            let evt = c.unwrap();
+            input_tx.send(Event::Input(evt)).unwrap();
+            if input_keymap.command(evt) == Some(keymap::Command::Quit) {
+                break;
+            }
+        }
+    });
+
+	// tick loop
+    thread::spawn(move || {
+        let tx = tx.clone();
+        loop {
+            tx.send(Event::Tick).unwrap();
+            thread::sleep(time::Duration::from_millis(500));
+        }
+	});
+
+	let periscope = client::Periscope::new(realms_client, keymap, offline);
+	periscope.run(&rx).expect("io error");
+}
@@ -1,4 +1,6 @@
 
+use rand::{thread_rng, Rng};
+
 use tokens::*;
 use utility::*;
 
@@ -12,10 +14,14 @@ pub enum RealmVariant {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RealmTemplate {
+    // the island is generated from it, the same seed makes the same island
+    pub seed: u64,
     pub regions: SelectionHashMap<Region>,
-    pub explorers: Vec<Explorer>
+    pub explorers: Vec<Explorer>,
+    pub characters: Vec<Character>
 }
 
+#[derive(Clone)]
 pub struct RealmStrategy {
     pub variant: RealmVariant,
     pub realm: Realm,
@@ -24,9 +30,10 @@ pub struct RealmStrategy {
 
 impl RealmStrategy {
     pub fn new(id: usize, variant: RealmVariant) -> RealmStrategy {
+        let seed = thread_rng().gen();
         match variant {
             RealmVariant::Tutorial => {
-                tutorial::new(id)
+                tutorial::new(id, seed)
             }
         }
     }
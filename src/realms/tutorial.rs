@@ -2,7 +2,8 @@
 use realms::RealmTemplate;
 use realms::RealmStrategy;
 use realms::RealmVariant;
-use rand::{thread_rng, distributions::Uniform, Rng};
+use rand::{distributions::Uniform, Rng, SeedableRng};
+use rand::rngs::StdRng;
 
 use tokens::*;
 use tokens::Equipment::*;
@@ -10,9 +11,14 @@ use utility::*;
 use itertools::Itertools;
 
 use hex::*;
-
-pub fn new(id: RealmId) -> RealmStrategy {
-	let template = template();
+use construction;
+use cartography;
+use claims;
+use ecology;
+use weather;
+
+pub fn new(id: RealmId, seed: u64) -> RealmStrategy {
+	let template = template(seed);
     RealmStrategy { variant: RealmVariant::Tutorial, realm: realm(id, &template), template }
 }
 
@@ -20,18 +26,46 @@ pub fn state(strategy: &mut RealmStrategy) {
     match strategy.variant {
         RealmVariant::Tutorial => {
 
+            ecology::regenerate(&mut strategy.template.regions, strategy.realm.age);
+            weather::change(&mut strategy.template.regions, strategy.realm.age);
+
+            // what the expedition saw before stays as it was last seen
             for (_, region) in strategy.realm.island.regions.iter_mut() {
-                region.resources = 0;
-                region.buildings = SelectionStorage::new();
-                region.particularities = SelectionStorage::new();
-                region.sight = RegionVisibility::None;
+                if region.seen.is_some() {
+                    region.sight = RegionVisibility::Remembered;
+                } else {
+                    region.resources = 0;
+                    region.buildings = SelectionStorage::new();
+                    region.particularities = SelectionStorage::new();
+                    region.owner = None;
+                    region.blockade = None;
+                    region.sight = RegionVisibility::None;
+                }
             }
             
+            // maps show regions as they were surveyed
+            let mut surveys = cartography::carried_surveys(&strategy.realm.expedition.explorers);
+            for (_, region) in strategy.template.regions.iter() {
+                if let Some(ref survey) = region.mapped {
+                    if !surveys.iter().any(|carried| carried.region == survey.region && carried.age >= survey.age) {
+                        surveys.push(survey.clone());
+                    }
+                }
+            }
+            for survey in surveys {
+                let remembered = match strategy.realm.island.regions.storage().get(&survey.region) {
+                    Some(known) => known.seen.is_some_and(|seen| seen > survey.age),
+                    None => false
+                };
+                if let (false, Some(region)) = (remembered, strategy.template.regions.storage().get(&survey.region)) {
+                    reveal(&mut strategy.realm.island.regions, &cartography::charted(region, &survey), RegionVisibility::Mapped);
+                }
+            }
+
             for (_, region) in strategy.template.regions.iter() {
-                if region.mapped {
-                    let mut region = region.clone();
-                    region.sight = RegionVisibility::Partial;
-                    strategy.realm.island.regions.insert(region.id, region.clone());
+                // a camp keeps an eye on its region
+                if region.has_building(Building::Camp) {
+                    reveal(&mut strategy.realm.island.regions, region, RegionVisibility::Complete);
                 }
             }
 
@@ -40,17 +74,26 @@ pub fn state(strategy: &mut RealmStrategy) {
                 if let Some(explorer_region) = explorer.region {
                     if let Some(explorer_region) = strategy.template.regions.storage().get(&explorer_region) {
                         
-                        for neighbor in &explorer_region.neighbors {
-                            if let Some(region) = strategy.template.regions.storage().get(&neighbor).clone() {
-                                let mut region = region.clone();
-                                region.sight = RegionVisibility::Partial;
-                                strategy.realm.island.regions.insert(region.id, region);
+                        // night, fog and storms close in around the explorer
+                        let mut sight: Vec<RegionId> = vec![];
+                        let mut ring = vec![explorer_region.id];
+                        for _ in 0..weather::sight(explorer_region, strategy.realm.age) {
+                            ring = ring.iter()
+                                .filter_map(|id| strategy.template.regions.storage().get(id))
+                                .flat_map(|region| region.neighbors.iter().cloned())
+                                .filter(|id| *id != explorer_region.id && !sight.contains(id))
+                                .unique()
+                                .collect();
+                            sight.extend(ring.iter());
+                        }
+
+                        for neighbor in &sight {
+                            if let Some(region) = strategy.template.regions.storage().get(neighbor) {
+                                reveal(&mut strategy.realm.island.regions, region, RegionVisibility::Partial);
                             }
                         }
 
-                        let mut region = explorer_region.clone();
-                        region.sight = RegionVisibility::Live;
-                        strategy.realm.island.regions.insert(region.id, region);
+                        reveal(&mut strategy.realm.island.regions, explorer_region, RegionVisibility::Live);
                     }
                 }
                 if explorer.region.is_some() {
@@ -58,9 +101,38 @@ pub fn state(strategy: &mut RealmStrategy) {
                 }
             }
 
-            if embarked == strategy.realm.expedition.explorers.iter().len() {
+            let age = strategy.realm.age;
+            for (_, region) in strategy.realm.island.regions.iter_mut() {
+                if region.sight >= RegionVisibility::Partial {
+                    region.seen = Some(age);
+                }
+            }
+
+            if embarked == strategy.realm.expedition.explorers.iter().len() && !strategy.realm.completed.contains(&RealmObjective::EmbarkExplorers) {
                 strategy.realm.completed.push(RealmObjective::EmbarkExplorers);
-                strategy.realm.story = "all explorers have embarked. you can keep playing around.".to_string();
+                strategy.realm.story = "all explorers have embarked. the farmers could use a well.".to_string();
+            }
+
+            let farmers_well = strategy.template.regions.iter().any(|(_, region)| {
+                region.has_particularity(Particularity::Farmers) && region.has_building(Building::Well)
+            });
+            if farmers_well && !strategy.realm.completed.contains(&RealmObjective::BuildWell) {
+                strategy.realm.completed.push(RealmObjective::BuildWell);
+                strategy.realm.story = "the farmers have their well.".to_string();
+            }
+
+            let claims = claims::claimed(&strategy.template.regions);
+            for objective in strategy.realm.objectives.clone() {
+                if let RealmObjective::ClaimRegions(regions) = objective {
+                    if claims >= regions && !strategy.realm.completed.contains(&objective) {
+                        strategy.realm.completed.push(objective);
+                        strategy.realm.story = format!("the expedition holds {} regions.", claims);
+                    }
+                }
+            }
+
+            if !strategy.realm.done && strategy.realm.objectives.iter().all(|objective| strategy.realm.completed.contains(objective)) {
+                strategy.realm.story = format!("{} you can keep playing around.", strategy.realm.story);
                 strategy.realm.done = true;
             }
             strategy.realm.age += 1;
@@ -68,11 +140,35 @@ pub fn state(strategy: &mut RealmStrategy) {
     }
 }  
 
-pub fn valid_move(strategy: &RealmStrategy, _explorer: ExplorerId, _region: RegionId) -> bool {
+// only ever raises the visibility of a region within one state update
+fn reveal(regions: &mut SelectionHashMap<Region>, region: &Region, sight: RegionVisibility) {
+    let (visible, seen) = match regions.storage().get(&region.id) {
+        Some(known) => (known.sight >= sight, known.seen),
+        None => (false, None)
+    };
+
+    if !visible {
+        let mut region = region.clone();
+        region.sight = sight;
+        region.seen = seen;
+        regions.insert(region.id, region);
+    }
+}
+
+pub fn valid_move(strategy: &RealmStrategy, explorer: ExplorerId, region: RegionId) -> bool {
     match strategy.variant {
         RealmVariant::Tutorial => {
-            // no movement restrictions for tutorial
-            true
+            // explorers walk to the regions next to theirs, blockades and rivers stand in the way.
+            // an explorer still on the ship lands anywhere
+            match (strategy.realm.expedition.explorers.storage().get(explorer), strategy.template.regions.storage().get(&region)) {
+                (_, Some(region)) if region.blockade.is_some() => false,
+                (Some(explorer), Some(region)) => {
+                    let from = explorer.region.and_then(|from| strategy.template.regions.storage().get(&from));
+                    let adjacent = from.map(|from| from.neighbors.contains(&region.id)).unwrap_or(true);
+                    adjacent && construction::river_crossing(explorer, from, region)
+                },
+                _ => false
+            }
         }
     }
 }
@@ -86,18 +182,31 @@ pub fn valid_action(strategy: &RealmStrategy, _explorer: ExplorerId, _region: Re
     }
 }
 
-fn template() -> RealmTemplate {
+fn template(seed: u64) -> RealmTemplate {
+    let mut regions = regions(seed);
+    let characters = characters(&mut regions);
 	RealmTemplate {
-        regions: regions(),
-        explorers: explorers()
+        seed,
+        regions,
+        explorers: explorers(seed),
+        characters
     }
 }
 
+// each part of the island draws from its own generator so they do not shift each other
+fn generator(seed: u64, part: u64) -> StdRng {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    bytes[8..16].copy_from_slice(&part.to_le_bytes());
+    StdRng::from_seed(bytes)
+}
+
 fn realm(id: usize, template: &RealmTemplate) -> Realm {
     let mut regions = SelectionHashMap::new();
     for (id, region) in template.regions.iter().take(2) {
         let mut region = region.clone();
         region.sight = RegionVisibility::Complete;
+        region.seen = Some(0);
         regions.insert(*id, region);
     }
     let island = Island {
@@ -108,22 +217,33 @@ fn realm(id: usize, template: &RealmTemplate) -> Realm {
         explorers: SelectionStorage::new_from(&template.explorers)
     };
 
+    let mut objectives = vec![RealmObjective::EmbarkExplorers, RealmObjective::BuildWell, RealmObjective::ClaimRegions(3)];
+    // the queen wants to hear of the first finding the island holds every clue to
+    let findable = Finding::all().into_iter().find(|finding| {
+        finding.clues().iter().all(|clue| template.regions.iter().any(|(_, region)| region.has_particularity(*clue)))
+    });
+    if let Some(finding) = findable {
+        objectives.push(RealmObjective::Report(finding));
+    }
+
     Realm {
         id,
         island,
         expedition,
+        characters: template.characters.clone(),
+        escorts: vec![],
         age: 0,
         title: "tutorial".to_string(),
         story: "".to_string(),
-        objectives: vec![RealmObjective::EmbarkExplorers],
+        objectives,
         completed: vec![],
         done: false
     }
 }
 
-fn regions() -> SelectionHashMap<Region> {
-    let mut rng = thread_rng();
-    let mut rng2 = thread_rng();
+fn regions(seed: u64) -> SelectionHashMap<Region> {
+    let mut rng = generator(seed, 0);
+    let mut rng2 = generator(seed, 1);
     let mut region_id = 0;
 
     let mut regions = SelectionHashMap::new();
@@ -239,9 +359,16 @@ fn regions() -> SelectionHashMap<Region> {
             terrain,
             particularities: SelectionStorage::new_from(&particularities),
             buildings: SelectionStorage::new(),
-            mapped: false,
+            mapped: None,
             resources,
             sight: RegionVisibility::None,
+            seen: None,
+            owner: None,
+            blockade: None,
+            modifications: vec![],
+            growth: 0,
+            depletion: 0,
+            weather: Weather::Clear,
             neighbors: vec![],
             hex_offset_coords: (0, 0)
         };
@@ -256,11 +383,32 @@ fn regions() -> SelectionHashMap<Region> {
         }
     }
 
+    // the farmers settle somewhere inland, waiting for their well
+    let inland: Vec<RegionId> = regions.iter().filter(|(_, region)| region.terrain != Terrain::Coast).map(|(id, _)| *id).collect();
+    let farmers = match inland.len() {
+        0 => rng.sample(Uniform::new(0, cols * rows)),
+        len => inland[rng.sample(Uniform::new(0, len))]
+    };
+    if let Some(region) = regions.storage_mut().get_mut(&farmers) {
+        region.particularities.insert(Particularity::Farmers);
+        region.owner = Some(Faction::Farmers);
+    }
+
+    // bandits hold a region somewhere past the landing
+    let hideouts: Vec<RegionId> = regions.iter().skip(2).filter(|(id, _)| **id != farmers).map(|(id, _)| *id).collect();
+    if !hideouts.is_empty() {
+        let hideout = hideouts[rng.sample(Uniform::new(0, hideouts.len()))];
+        if let Some(region) = regions.storage_mut().get_mut(&hideout) {
+            region.owner = Some(Faction::Bandits);
+            region.blockade = Some(Blockade { faction: Faction::Bandits, strength: 3, toll: Coins(20) });
+        }
+    }
+
     regions
 }
 
-fn explorers() -> Vec<Explorer> {
-    let mut rng = thread_rng();
+fn explorers(seed: u64) -> Vec<Explorer> {
+    let mut rng = generator(seed, 2);
 
     // Pots
     // Tinder
@@ -299,7 +447,8 @@ fn explorers() -> Vec<Explorer> {
             ExplorerItem::Equipment(Knife),
             ExplorerItem::Equipment(Coins(110)),
             ExplorerItem::Equipment(Telescope),
-            ExplorerItem::Equipment(Herbs(20))])
+            ExplorerItem::Equipment(Herbs(20))]),
+        trail: vec![]
     });
     explorers.push(Explorer {
         id: 1,
@@ -310,7 +459,8 @@ fn explorers() -> Vec<Explorer> {
             ExplorerItem::Equipment(Food(10)),
             ExplorerItem::Equipment(Pipe),
             ExplorerItem::Equipment(Blankets),
-            ExplorerItem::Equipment(Knife)])
+            ExplorerItem::Equipment(Knife)]),
+        trail: vec![]
     });
     explorers.push(Explorer {
         id: 2,
@@ -321,7 +471,8 @@ fn explorers() -> Vec<Explorer> {
             ExplorerItem::Equipment(Tinder),
             ExplorerItem::Equipment(Firewood(4)),
             ExplorerItem::Equipment(Flint),
-            ExplorerItem::Equipment(Rope)])
+            ExplorerItem::Equipment(Rope)]),
+        trail: vec![]
     });
 
     if how_many_explorers > 3 {
@@ -334,7 +485,8 @@ fn explorers() -> Vec<Explorer> {
                 ExplorerItem::Equipment(Map),
                 ExplorerItem::Equipment(Rope),
                 ExplorerItem::Equipment(Wax),
-                ExplorerItem::Equipment(SealStamp)])
+                ExplorerItem::Equipment(SealStamp)]),
+            trail: vec![]
         });
     }
 
@@ -349,7 +501,8 @@ fn explorers() -> Vec<Explorer> {
                 ExplorerItem::Equipment(Rope),
                 ExplorerItem::Equipment(Knife),
                 ExplorerItem::Equipment(Compass),
-                ExplorerItem::Equipment(Telescope)])
+                ExplorerItem::Equipment(Telescope)]),
+            trail: vec![]
         };
         let canoe_or_not = rng.sample(&Uniform::new_inclusive(0, 1));
         if canoe_or_not == 1 {
@@ -359,4 +512,299 @@ fn explorers() -> Vec<Explorer> {
     }
 
     explorers
-}
\ No newline at end of file
+}
+
+fn characters(regions: &mut SelectionHashMap<Region>) -> Vec<Character> {
+    let mut characters = vec![];
+    let farmers = regions.iter().find(|(_, region)| region.has_particularity(Particularity::Farmers)).map(|(id, _)| *id);
+
+    // the queen sees the expedition off where it lands
+    if let Some((_, region)) = regions.iter_mut().next() {
+        region.particularities.insert(Particularity::Queen);
+        region.owner = Some(Faction::Crown);
+        characters.push(Character {
+            id: 0,
+            name: "the queen".to_string(),
+            text: "she sent the expedition to the island.".to_string(),
+            region: region.id,
+            particularity: Particularity::Queen,
+            dialog: vec![
+                DialogNode {
+                    id: 0,
+                    text: "so you made it ashore. what do you need?".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "what are we here for?".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: Some(1)
+                        },
+                        DialogChoice {
+                            text: "we are short on parchment.".to_string(),
+                            conditions: vec![DialogCondition::AffectionAtLeast(1), DialogCondition::AffectionBelow(2)],
+                            effects: vec![
+                                DialogEffect::GiveItem(ExplorerItem::Equipment(Parchment(3))),
+                                DialogEffect::Affection(1)
+                            ],
+                            next: Some(2)
+                        },
+                        DialogChoice {
+                            text: "the farmers have their well.".to_string(),
+                            conditions: vec![DialogCondition::Completed(RealmObjective::BuildWell), DialogCondition::AffectionBelow(5)],
+                            effects: vec![
+                                DialogEffect::GiveItem(ExplorerItem::Equipment(Gold(2))),
+                                DialogEffect::Affection(5),
+                                DialogEffect::Story("the queen is pleased with the expedition.".to_string())
+                            ],
+                            next: Some(2)
+                        },
+                        DialogChoice {
+                            text: "any errands?".to_string(),
+                            conditions: vec![DialogCondition::HasErrand],
+                            effects: vec![DialogEffect::Errand],
+                            next: Some(3)
+                        },
+                        DialogChoice {
+                            text: "nothing.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 1,
+                    text: "get everyone ashore, then see to the farmers inland. they are in need of water.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "we will see to it.".to_string(),
+                            conditions: vec![DialogCondition::AffectionBelow(1)],
+                            effects: vec![DialogEffect::Affection(1)],
+                            next: None
+                        },
+                        DialogChoice {
+                            text: "understood.".to_string(),
+                            conditions: vec![DialogCondition::AffectionAtLeast(1)],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 2,
+                    text: "take this and carry on.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "thank you.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 3,
+                    text: "see that it gets where it belongs.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "consider it done.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                }
+            ],
+            affection: 0,
+            interests: Finding::all(),
+            errands: match farmers {
+                Some(_) => vec![Delivery {
+                    cargo: Cargo::Message("the crown has not forgotten you.".to_string()),
+                    destination: Destination::Character(1, "the farmers".to_string()),
+                    client: 0,
+                    reward: vec![Coins(10)]
+                }],
+                None => vec![]
+            },
+            conversation: None
+        });
+    }
+
+    let queen = characters.first().map(|queen: &Character| queen.region);
+    if let Some(region) = farmers.and_then(|farmers| regions.storage().get(&farmers)) {
+        characters.push(Character {
+            id: 1,
+            name: "the farmers".to_string(),
+            text: "they work the land but have to carry their water a long way.".to_string(),
+            region: region.id,
+            particularity: Particularity::Farmers,
+            dialog: vec![
+                DialogNode {
+                    id: 0,
+                    text: "strangers! are you here to help?".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "what do you need?".to_string(),
+                            conditions: vec![DialogCondition::AffectionBelow(1)],
+                            effects: vec![DialogEffect::Affection(1)],
+                            next: Some(1)
+                        },
+                        DialogChoice {
+                            text: "here is some food.".to_string(),
+                            conditions: vec![DialogCondition::Carries(Food(2))],
+                            effects: vec![DialogEffect::TakeEquipment(Food(2)), DialogEffect::Affection(1)],
+                            next: Some(2)
+                        },
+                        DialogChoice {
+                            text: "the well is done.".to_string(),
+                            conditions: vec![DialogCondition::Completed(RealmObjective::BuildWell), DialogCondition::AffectionBelow(5)],
+                            effects: vec![
+                                DialogEffect::GiveItem(ExplorerItem::Equipment(Herbs(5))),
+                                DialogEffect::Affection(5)
+                            ],
+                            next: Some(2)
+                        },
+                        DialogChoice {
+                            text: "can we help with anything else?".to_string(),
+                            conditions: vec![DialogCondition::AffectionAtLeast(1), DialogCondition::HasErrand],
+                            effects: vec![DialogEffect::Errand],
+                            next: Some(3)
+                        },
+                        DialogChoice {
+                            text: "farewell.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 1,
+                    text: "a well. rope and firewood is all it takes, and someone who knows how to build.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "we will build one.".to_string(),
+                            conditions: vec![],
+                            effects: vec![DialogEffect::Story("the farmers are waiting for their well.".to_string())],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 2,
+                    text: "bless you.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "farewell.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 3,
+                    text: "we would be ever so grateful.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "farewell.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                }
+            ],
+            affection: 0,
+            interests: vec![Finding::RiverSource, Finding::HiddenValley],
+            errands: match queen {
+                Some(queen) => vec![
+                    Delivery {
+                        cargo: Cargo::Goods(Herbs(3)),
+                        destination: Destination::Character(0, "the queen".to_string()),
+                        client: 1,
+                        reward: vec![Gold(1)]
+                    },
+                    Delivery {
+                        cargo: Cargo::Person("a farmer's child".to_string()),
+                        destination: Destination::Region(queen),
+                        client: 1,
+                        reward: vec![Parchment(2)]
+                    }
+                ],
+                None => vec![]
+            },
+            conversation: None
+        });
+    }
+
+    // an elder of the farmers wants to see the queen but does not know the way
+    if let (Some(farmers), Some(queen)) = (farmers, queen) {
+        if let Some(region) = regions.storage_mut().get_mut(&farmers) {
+            region.particularities.insert(Particularity::Character);
+        }
+        characters.push(Character {
+            id: 2,
+            name: "the elder".to_string(),
+            text: "the oldest of the farmers, walking with a stick.".to_string(),
+            region: farmers,
+            particularity: Particularity::Character,
+            dialog: vec![
+                DialogNode {
+                    id: 0,
+                    text: "i must speak with the queen, but i do not know the way.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "we will take you to her.".to_string(),
+                            conditions: vec![DialogCondition::AffectionBelow(2)],
+                            effects: vec![DialogEffect::Escort(queen)],
+                            next: Some(1)
+                        },
+                        DialogChoice {
+                            text: "here we are.".to_string(),
+                            conditions: vec![DialogCondition::AffectionAtLeast(2)],
+                            effects: vec![DialogEffect::Story("the elder has spoken with the queen.".to_string())],
+                            next: Some(2)
+                        },
+                        DialogChoice {
+                            text: "not now.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 1,
+                    text: "lead the way, i will follow as best i can.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "stay close.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                },
+                DialogNode {
+                    id: 2,
+                    text: "safe and sound. i will not forget this.".to_string(),
+                    choices: vec![
+                        DialogChoice {
+                            text: "farewell.".to_string(),
+                            conditions: vec![],
+                            effects: vec![],
+                            next: None
+                        }
+                    ]
+                }
+            ],
+            affection: 0,
+            interests: vec![],
+            errands: vec![],
+            conversation: None
+        });
+    }
+
+    characters
+}
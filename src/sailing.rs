@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+
+use rand::{thread_rng, distributions::Uniform, Rng};
+
+use tokens::*;
+use utility::*;
+use weather;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Vessel {
+    Canoe,
+    Raft,
+    Ship
+}
+
+impl Vessel {
+    // how many sea legs a voyage may take
+    pub fn range(&self) -> usize {
+        match self {
+            Vessel::Canoe => 2,
+            Vessel::Raft => 3,
+            Vessel::Ship => 6
+        }
+    }
+
+    // explorers that can come along besides the sailor
+    pub fn passengers(&self) -> usize {
+        match self {
+            Vessel::Canoe => 0,
+            Vessel::Raft => 1,
+            Vessel::Ship => 3
+        }
+    }
+
+    fn seaworthiness(&self) -> usize {
+        match self {
+            Vessel::Canoe => 65,
+            Vessel::Raft => 75,
+            Vessel::Ship => 90
+        }
+    }
+}
+
+pub enum Voyage {
+    Arrived,
+    TurnedBack
+}
+
+// the best vessel the explorer has at hand in the region
+pub fn vessel(explorer: &Explorer, region: &Region) -> Option<Vessel> {
+    if region.has_particularity(Particularity::Ship) {
+        Some(Vessel::Ship)
+    } else if explorer.has_equipment(&Equipment::Raft) {
+        Some(Vessel::Raft)
+    } else if explorer.has_equipment(&Equipment::Canoe) {
+        Some(Vessel::Canoe)
+    } else {
+        None
+    }
+}
+
+// coast regions and the edge of the island can be reached from the sea
+pub fn seaworthy(region: &Region) -> bool {
+    region.terrain == Terrain::Coast || region.neighbors.len() < 6 || region.has_particularity(Particularity::Island)
+}
+
+// sea legs from a region: along the coast to neighboring coast regions and off-shore to islands up to two regions away.
+fn sea_legs(regions: &SelectionHashMap<Region>, region: &Region) -> Vec<RegionId> {
+    let mut legs = vec![];
+
+    for neighbor in &region.neighbors {
+        if let Some(neighbor) = regions.storage().get(neighbor) {
+            if seaworthy(neighbor) {
+                legs.push(neighbor.id);
+            }
+            for off_shore in &neighbor.neighbors {
+                if let Some(off_shore) = regions.storage().get(off_shore) {
+                    if off_shore.id != region.id && off_shore.has_particularity(Particularity::Island) {
+                        legs.push(off_shore.id);
+                    }
+                }
+            }
+        }
+    }
+
+    legs
+}
+
+// every region a vessel can reach from the given one, never passing the interior.
+pub fn destinations(regions: &SelectionHashMap<Region>, from: RegionId, vessel: Vessel) -> Vec<RegionId> {
+    let mut reached: Vec<RegionId> = vec![];
+    let mut queue = VecDeque::new();
+
+    match regions.storage().get(&from) {
+        Some(region) if seaworthy(region) => queue.push_back((from, 0)),
+        _ => return reached
+    }
+
+    while let Some((id, legs)) = queue.pop_front() {
+        if legs >= vessel.range() {
+            continue;
+        }
+        if let Some(region) = regions.storage().get(&id) {
+            for leg in sea_legs(regions, region) {
+                if leg != from && !reached.contains(&leg) {
+                    reached.push(leg);
+                    queue.push_back((leg, legs + 1));
+                }
+            }
+        }
+    }
+
+    reached.sort();
+    reached
+}
+
+// percent chance to arrive safely, a haven or a lighthouse makes it better and the weather at either end worse
+pub fn chance(vessel: Vessel, from: &Region, to: &Region) -> usize {
+    let lighthouse = |region: &Region| region.has_building(Building::Lighthouse) || region.has_particularity(Particularity::Lighthouse);
+
+    let mut chance = vessel.seaworthiness();
+    if to.has_particularity(Particularity::Haven) {
+        chance += 15;
+    }
+    if lighthouse(from) || lighthouse(to) {
+        chance += 20;
+    }
+    chance.min(100).saturating_sub(weather::sailing(from, to))
+}
+
+pub fn seafaring(explorer: &Explorer, regions: &SelectionHashMap<Region>, destination: RegionId) -> Result<(Vessel, usize), String> {
+    if !explorer.has_trait(&ExplorerTrait::Sailor) {
+        return Err(format!("only a {} can set sail.", ExplorerTrait::Sailor));
+    }
+
+    let from = match explorer.region.and_then(|region| regions.storage().get(&region)) {
+        Some(region) => region,
+        None => return Err("the explorer has not embarked yet.".to_string())
+    };
+    let to = match regions.storage().get(&destination) {
+        Some(region) => region,
+        None => return Err("the destination is unknown.".to_string())
+    };
+
+    let vessel = match vessel(explorer, from) {
+        Some(vessel) => vessel,
+        None => return Err("there is nothing to sail with.".to_string())
+    };
+    if !destinations(regions, from.id, vessel).contains(&destination) {
+        return Err(format!("region {} is out of reach by {:?}.", destination, vessel));
+    }
+
+    Ok((vessel, chance(vessel, from, to)))
+}
+
+// the sailor takes passengers from the region along, lowest explorer id first.
+pub fn sail(explorer: ExplorerId, destination: RegionId, explorers: &mut SelectionStorage<Explorer>, regions: &SelectionHashMap<Region>, age: usize) -> Result<Voyage, String> {
+    let (vessel, chance, origin) = match explorers.storage().get(explorer) {
+        Some(sailor) => {
+            let (vessel, chance) = seafaring(sailor, regions, destination)?;
+            (vessel, chance, sailor.region)
+        },
+        None => return Err("there is no such explorer.".to_string())
+    };
+
+    let mut rng = thread_rng();
+    if rng.sample(Uniform::new(0, 100)) >= chance {
+        return Ok(Voyage::TurnedBack);
+    }
+
+    let passengers: Vec<ExplorerId> = explorers.iter()
+        .filter(|passenger| passenger.id != explorer && passenger.region == origin)
+        .map(|passenger| passenger.id)
+        .take(vessel.passengers())
+        .collect();
+
+    for crew in explorers.iter_mut() {
+        if crew.id == explorer {
+            crew.note_action(&ExplorerAction::Sail(destination));
+        }
+        if crew.id == explorer || passengers.contains(&crew.id) {
+            crew.track(destination, age);
+        }
+    }
+
+    Ok(Voyage::Arrived)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixtures::*;
+
+    // a coast from 1 to 5 around the planes (10) in the interior, an island (20) lies off the planes.
+    fn shore() -> SelectionHashMap<Region> {
+        regions(vec![
+            region(1, Terrain::Coast, vec![], vec![2, 10]),
+            region(2, Terrain::Coast, vec![], vec![1, 3, 10]),
+            region(3, Terrain::Coast, vec![], vec![2, 4, 10]),
+            region(4, Terrain::Coast, vec![], vec![3, 5]),
+            region(5, Terrain::Coast, vec![], vec![4]),
+            region(10, Terrain::Planes, vec![], vec![1, 2, 3, 11, 12, 20]),
+            region(20, Terrain::Planes, vec![Particularity::Island], vec![10])
+        ])
+    }
+
+    #[test]
+    fn the_range_of_the_vessel_limits_the_sea_legs() {
+        let regions = shore();
+        assert_eq!(destinations(&regions, 1, Vessel::Canoe), vec![2, 3, 20]);
+        assert_eq!(destinations(&regions, 1, Vessel::Raft), vec![2, 3, 4, 20]);
+        assert_eq!(destinations(&regions, 1, Vessel::Ship), vec![2, 3, 4, 5, 20]);
+    }
+
+    #[test]
+    fn no_voyage_starts_in_the_interior() {
+        let regions = shore();
+        assert!(destinations(&regions, 10, Vessel::Ship).is_empty());
+        assert!(!destinations(&regions, 20, Vessel::Ship).contains(&10));
+    }
+    #[test]
+    fn a_lighthouse_adds_to_the_seaworthiness() {
+        let regions = shore();
+        let mut lit = regions.storage()[&2].clone();
+        lit.particularities.insert(Particularity::Lighthouse);
+        assert_eq!(chance(Vessel::Canoe, &regions.storage()[&1], &regions.storage()[&2]), 65);
+        assert_eq!(chance(Vessel::Canoe, &regions.storage()[&1], &lit), 85);
+        assert_eq!(chance(Vessel::Ship, &regions.storage()[&1], &lit), 100);
+
+        lit.weather = Weather::Storm;
+        assert_eq!(chance(Vessel::Ship, &regions.storage()[&1], &lit), 60);
+    }
+}
@@ -0,0 +1,351 @@
+use std::io::BufRead;
+
+use uuid::Uuid;
+use serde_json;
+
+use tokens::*;
+use client_api::RealmsClient;
+use actions;
+use crafting;
+use dialog;
+use investigation;
+use delivery;
+
+// a client without a terminal, reading one command per line and printing a json line per answer.
+// the first failing command ends the script.
+pub struct Script {
+	client: RealmsClient,
+	// whether the server knows the client yet
+	registered: bool,
+	realm: Option<Realm>,
+	// the explorer orders go to, the last one named
+	explorer: Option<ExplorerId>
+}
+
+impl Script {
+	pub fn new(client: RealmsClient) -> Script {
+		Script {
+			client,
+			registered: false,
+			realm: None,
+			explorer: None
+		}
+	}
+
+	pub fn run<R: BufRead>(mut self, input: R) -> Result<(), String> {
+		for (number, line) in input.lines().enumerate() {
+			let line = line.map_err(|error| format!("could not read the script: {}", error))?;
+			let command = line.trim();
+			if command.is_empty() || command.starts_with('#') {
+				continue;
+			}
+
+			let result = self.request(command).and_then(|response| match response {
+				RealmsProtocol::Rejected(reason) => Err(reason),
+				response => Response::new(&response).map(|output| (response, output))
+			});
+
+			let (output, quit) = match result {
+				Ok((response, output)) => {
+					self.answered(&response);
+					// the server lets the client go on quit
+					let quit = matches!(response, RealmsProtocol::Quit);
+					(Output { line: number + 1, command, status: "ok", reason: None, response: Some(output) }, Ok(quit))
+				},
+				Err(reason) => (Output { line: number + 1, command, status: "error", reason: Some(reason.clone()), response: None }, Err(reason))
+			};
+			println!("{}", serde_json::to_string(&output).map_err(|error| format!("could not write the answer: {}", error))?);
+
+			match quit {
+				Ok(true) => break,
+				Ok(false) => { },
+				Err(reason) => return Err(format!("line {}: {}", number + 1, reason))
+			}
+		}
+
+		Ok(())
+	}
+
+	// keeps track of the realm played in
+	fn answered(&mut self, response: &RealmsProtocol) {
+		if let RealmsProtocol::Realm(realm) = response {
+			if self.realm.as_ref().map(|played| played.id) != Some(realm.id) {
+				self.explorer = None;
+			}
+			self.realm = Some(realm.clone());
+		}
+	}
+
+	// sends a line of the script to the server, registering the client first if the server does not know it yet
+	fn request(&mut self, command: &str) -> Result<RealmsProtocol, String> {
+		let words: Vec<&str> = command.split_whitespace().collect();
+		let identified = match words.as_slice() {
+			["register"] => Some(self.client.register()),
+			["connect", client] => {
+				let client = Uuid::parse_str(client).map_err(|_| format!("{} is no client id.", client))?;
+				Some(self.client.connect(client))
+			},
+			_ => None
+		};
+		if let Some(identified) = identified {
+			let id = identified?;
+			self.registered = true;
+			return Ok(RealmsProtocol::Connect(id));
+		}
+
+		if !self.registered {
+			self.client.register()?;
+			self.registered = true;
+		}
+
+		let request = self.parse(&words, command)?;
+		self.client.send(request)
+	}
+
+	// turns a line of the script into a request
+	fn parse(&mut self, words: &[&str], command: &str) -> Result<RealmsProtocol, String> {
+		match words {
+			["quit"] => Ok(RealmsProtocol::Quit),
+			["new", "realm"] => Ok(RealmsProtocol::RequestNewRealm),
+			["realms"] => Ok(RealmsProtocol::RequestRealmsList),
+			["resume", "realm", realm] => Ok(RealmsProtocol::RequestRealm(number(realm)?)),
+			["archive", "realm", realm] => Ok(RealmsProtocol::ArchiveRealm(number(realm)?)),
+			["delete", "realm", realm] => Ok(RealmsProtocol::DeleteRealm(number(realm)?)),
+			["duplicate", "realm", realm] => Ok(RealmsProtocol::DuplicateRealm(number(realm)?)),
+			["explorer", explorer] => {
+				// answered with the realm as it is now
+				self.select(number(explorer)?)?;
+				Ok(RealmsProtocol::RequestRealm(self.played()?.id))
+			},
+			["move", "explorer", explorer, "to", "region", region] => {
+				let explorer = self.select(number(explorer)?)?;
+				Ok(RealmsProtocol::Explorer(Move::ChangeRegion(self.played()?.id, number(region)?, explorer)))
+			},
+			["follow", "explorer", tracked] => {
+				let (realm, explorer) = self.order()?;
+				Ok(RealmsProtocol::Explorer(Move::Follow(realm.id, explorer.id, number(tracked)?)))
+			},
+			["act", ..] => {
+				let (realm, explorer) = self.order()?;
+				let region = here(realm, explorer)?;
+				let action = named(actions::available(explorer, region, &realm.island.regions), &words[1..], |action| format!("{}", action))?;
+				Ok(RealmsProtocol::Explorer(Move::Action(realm.id, region.id, explorer.id, action)))
+			},
+			["pick", ..] => {
+				let (realm, explorer) = self.order()?;
+				let region = here(realm, explorer)?;
+				let items: Vec<Equipment> = region.particularities.iter().filter_map(|particularity| match particularity {
+					Particularity::Item(equipment) => Some(*equipment),
+					_ => None
+				}).collect();
+				let equipment = named(items, &words[1..], |equipment| format!("{:?}", equipment))?;
+				Ok(RealmsProtocol::PickEquipment(realm.id, region.id, explorer.id, equipment))
+			},
+			["drop", ..] => {
+				let (realm, explorer) = self.order()?;
+				let region = here(realm, explorer)?;
+				let items: Vec<Equipment> = explorer.inventory.iter().filter_map(|item| match item {
+					ExplorerItem::Equipment(equipment) => Some(*equipment),
+					_ => None
+				}).collect();
+				let equipment = named(items, &words[1..], |equipment| format!("{:?}", equipment))?;
+				Ok(RealmsProtocol::DropEquipment(realm.id, region.id, explorer.id, equipment))
+			},
+			["investigate", ..] => {
+				let (realm, explorer) = self.order()?;
+				let region = here(realm, explorer)?;
+				let particularity = named(region.particularities.storage().clone(), &words[1..], |particularity| format!("{:?}", particularity))?;
+				Ok(RealmsProtocol::InvestigateParticularity(realm.id, region.id, explorer.id, particularity))
+			},
+			["forget", ..] => {
+				let (realm, explorer) = self.order()?;
+				let remembered: Vec<(RegionId, Particularity)> = explorer.inventory.iter().filter_map(|item| match item {
+					ExplorerItem::Particularity(region, particularity) => Some((*region, *particularity)),
+					_ => None
+				}).collect();
+				let (region, particularity) = named(remembered, &words[1..], |(region, particularity)| format!("{:?} at {}", particularity, region))?;
+				Ok(RealmsProtocol::ForgetParticularity(realm.id, region, explorer.id, particularity))
+			},
+			["craft", ..] => {
+				let (realm, explorer) = self.order()?;
+				let region = here(realm, explorer)?;
+				let recipe = named(crafting::recipes(), &words[1..], |recipe| format!("{}", recipe))?;
+				Ok(RealmsProtocol::Craft(realm.id, region.id, explorer.id, recipe.id))
+			},
+			["give", .., "to", "explorer", recipient] => {
+				let (realm, explorer) = self.order()?;
+				let item = named(explorer.inventory.storage().clone(), &words[1..words.len() - 3], |item| format!("{}", item))?;
+				Ok(RealmsProtocol::GiveItem(realm.id, explorer.id, number(recipient)?, item))
+			},
+			["talk", "to", ..] => {
+				let (realm, explorer) = self.order()?;
+				let present: Vec<&Character> = dialog::present(explorer, &realm.characters).iter().filter_map(|id| realm.characters.get(*id)).collect();
+				let character = named(present, &words[2..], |character| character.name.clone())?;
+				Ok(RealmsProtocol::Talk(realm.id, explorer.id, character.id))
+			},
+			["answer", choice] => {
+				let (realm, explorer) = self.order()?;
+				let character = realm.characters.iter().find(|character| dialog::current(character, explorer.id).is_some())
+					.ok_or_else(|| format!("explorer {} is not talking to anyone.", explorer.id))?;
+				let choices = dialog::choices(character, explorer, &realm.completed);
+				// answers are counted from one, as the journal does
+				let choice = number(choice)?.checked_sub(1).and_then(|position| choices.get(position).cloned())
+					.ok_or_else(|| format!("{} has no answer {}.", character.name, choice))?;
+				Ok(RealmsProtocol::Answer(realm.id, explorer.id, character.id, choice))
+			},
+			["report", ..] => {
+				let (realm, explorer) = self.order()?;
+				let (character, finding) = named(investigation::reports(explorer, &realm.characters), &words[1..], |(_, finding)| format!("{}", finding))?;
+				Ok(RealmsProtocol::Report(realm.id, explorer.id, character, finding))
+			},
+			["deliver", ..] => {
+				let (realm, explorer) = self.order()?;
+				let delivery = named(delivery::carried(explorer), &words[1..], |delivery| format!("{}", delivery))?;
+				Ok(RealmsProtocol::Deliver(realm.id, explorer.id, delivery))
+			},
+			_ => Err(format!("there is no command {}.", command))
+		}
+	}
+
+	fn played(&self) -> Result<&Realm, String> {
+		self.realm.as_ref().ok_or_else(|| "no realm is played yet, start with new realm or resume realm.".to_string())
+	}
+
+	fn select(&mut self, explorer: ExplorerId) -> Result<ExplorerId, String> {
+		if !self.played()?.expedition.explorers.iter().any(|member| member.id == explorer) {
+			return Err(format!("there is no explorer {}.", explorer));
+		}
+		self.explorer = Some(explorer);
+		Ok(explorer)
+	}
+
+	// the realm and the explorer an order is given in
+	fn order(&self) -> Result<(&Realm, &Explorer), String> {
+		let realm = self.played()?;
+		let id = self.explorer.ok_or_else(|| "no explorer is named yet, name one with explorer <id>.".to_string())?;
+		let explorer = realm.expedition.explorers.iter().find(|explorer| explorer.id == id)
+			.ok_or_else(|| format!("there is no explorer {}.", id))?;
+		Ok((realm, explorer))
+	}
+}
+
+fn number(word: &str) -> Result<usize, String> {
+	word.parse().map_err(|_| format!("{} is no number.", word))
+}
+
+fn here<'a>(realm: &'a Realm, explorer: &Explorer) -> Result<&'a Region, String> {
+	explorer.region.and_then(|region| realm.island.regions.storage().get(&region))
+		.ok_or_else(|| format!("explorer {} has not embarked yet.", explorer.id))
+}
+
+// the first candidate whose text starts with the given words, ignoring case: rope names Rope, firewood names Firewood(3)
+fn named<T, F: Fn(&T) -> String>(candidates: Vec<T>, words: &[&str], text: F) -> Result<T, String> {
+	let name = words.join(" ");
+	let lowercase = name.to_lowercase();
+	if name.is_empty() {
+		return Err("a name is missing.".to_string());
+	}
+	let known: Vec<String> = candidates.iter().map(text).collect();
+	let position = known.iter().position(|known| {
+		let known = known.to_lowercase();
+		known == lowercase || (known.starts_with(&lowercase) && !known[lowercase.len()..].starts_with(char::is_alphanumeric))
+	});
+	match position {
+		Some(position) => Ok(candidates.into_iter().nth(position).expect("the position was found among the candidates.")),
+		None if known.is_empty() => Err(format!("there is nothing named {} here.", name)),
+		None => Err(format!("there is nothing named {} here, only {}.", name, known.join(", ")))
+	}
+}
+
+// a json line for every line of the script
+#[derive(Serialize)]
+struct Output<'a> {
+	line: usize,
+	command: &'a str,
+	status: &'static str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	reason: Option<String>,
+	#[serde(flatten)]
+	response: Option<Response>
+}
+
+// what the server answered, named by the response field
+#[derive(Serialize)]
+#[serde(tag = "response", rename_all = "lowercase")]
+enum Response {
+	Connect { client: String },
+	Realms { realms: Vec<RealmsEntry> },
+	Realm { realm: RealmOutput },
+	Quit
+}
+
+#[derive(Serialize)]
+struct RealmsEntry {
+	id: RealmId,
+	title: String,
+	age: usize,
+	completed: usize,
+	objectives: usize,
+	done: bool,
+	archived: bool
+}
+
+#[derive(Serialize)]
+struct RealmOutput {
+	id: RealmId,
+	title: String,
+	age: usize,
+	story: String,
+	objectives: Vec<String>,
+	completed: Vec<String>,
+	done: bool,
+	explorers: Vec<ExplorerOutput>
+}
+
+#[derive(Serialize)]
+struct ExplorerOutput {
+	id: ExplorerId,
+	region: Option<RegionId>,
+	traits: Vec<String>,
+	inventory: Vec<String>
+}
+
+impl Response {
+	// an answer the script cannot go on with is an error, like the server answering nothing at all
+	fn new(response: &RealmsProtocol) -> Result<Response, String> {
+		match response {
+			RealmsProtocol::Connect(client) => Ok(Response::Connect { client: client.to_string() }),
+			RealmsProtocol::RealmsList(realms) => Ok(Response::Realms {
+				realms: realms.iter().map(|summary| RealmsEntry {
+					id: summary.id,
+					title: summary.title.clone(),
+					age: summary.age,
+					completed: summary.completed,
+					objectives: summary.objectives,
+					done: summary.done,
+					archived: summary.archived
+				}).collect()
+			}),
+			RealmsProtocol::Realm(realm) => Ok(Response::Realm {
+				realm: RealmOutput {
+					id: realm.id,
+					title: realm.title.clone(),
+					age: realm.age,
+					story: realm.story.clone(),
+					objectives: realm.objectives.iter().map(|objective| format!("{}", objective)).collect(),
+					completed: realm.completed.iter().map(|objective| format!("{}", objective)).collect(),
+					done: realm.done,
+					explorers: realm.expedition.explorers.iter().map(|explorer| ExplorerOutput {
+						id: explorer.id,
+						region: explorer.region,
+						traits: explorer.traits.iter().map(|explorer_trait| format!("{}", explorer_trait)).collect(),
+						inventory: explorer.inventory.iter().map(|item| format!("{}", item)).collect()
+					}).collect()
+				}
+			}),
+			RealmsProtocol::Quit => Ok(Response::Quit),
+			RealmsProtocol::Void => Err("the server did not answer.".to_string()),
+			response => Err(format!("the server answered {}.", response))
+		}
+	}
+}
@@ -1,16 +1,14 @@
 
-use std::collections::HashMap;
-use std::net::TcpStream;
-use std::net::TcpListener;
-use std::net::Shutdown;
-use std::io::prelude::*;
+use std::collections::{HashMap, BTreeMap};
 use std::thread;
-use std::sync::{Mutex, Arc, mpsc};
-use std::io;
-
-use bincode::{serialize, deserialize};
+use std::sync::{Mutex, Arc};
+use std::sync::mpsc::Sender;
+#[cfg(feature = "cli")]
+use std::sync::mpsc;
 
+#[cfg(feature = "cli")]
 use tui::Terminal;
+#[cfg(feature = "cli")]
 use tui::backend::RawBackend;
 
 use chrono::{Local, DateTime};
@@ -20,12 +18,24 @@ use uuid::Uuid;
 use tokens::*;
 use utility::*;
 use realms::*;
+#[cfg(feature = "cli")]
 use server_dashboard::*;
+use transport::{self, Connection, ChannelTransport};
+use crafting;
+use actions;
+use tracking;
+use dialog;
+use investigation;
+use delivery;
+use escort;
+use weather;
 
 pub struct Universe {
-	pub realms: Vec<RealmStrategy>,
+	pub realms: BTreeMap<RealmId, RealmStrategy>,
 	pub clients: HashMap<Uuid, Client>,
-	pub requests: Vec<(ClientId, RealmsProtocol, DateTime<Local>)>
+	pub requests: Vec<(ClientId, RealmsProtocol, DateTime<Local>)>,
+	// realm ids are never given out twice, not even after a realm was deleted
+	next_realm: RealmId
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +44,9 @@ pub struct Client {
 	pub connected: bool,
 	pub time: DateTime<Local>,
 	pub realms_list: SelectionStorage<RealmId>,
+	pub archived: Vec<RealmId>,
+	// when the client last sent a request to each of its realms
+	pub played: HashMap<RealmId, DateTime<Local>>,
 	pub completed_variants: Vec<RealmVariant>
 }
 
@@ -44,67 +57,73 @@ impl Client {
 			connected: true,
 			time: Local::now(),
 			realms_list: SelectionStorage::new(),
+			archived: vec![],
+			played: HashMap::new(),
 			completed_variants: vec![]
 		}
 	}
 }
 
+impl Universe {
+	pub fn new() -> Universe {
+		Universe { realms: BTreeMap::new(), clients: HashMap::new(), requests: vec![], next_realm: 0 }
+	}
+
+	// answers the request of a client and tells whether the client disconnected with it
+	pub fn handle(&mut self, client_id: ClientId, request: RealmsProtocol) -> (RealmsProtocol, bool) {
+		// fetch current client if any
+		let current_client: Option<Client> = self.clients.get(&client_id).cloned();
+
+		// seperate client and no-client request handling
+		match current_client {
+			Some(mut client) => {
+				let response = handle_request(&mut self.realms, &mut self.next_realm, &self.clients, &mut client, request.clone());
+				let disconnect = !client.connected;
+
+				// log request
+				self.requests.push((client.id, request, Local::now()));
+				// update client in list
+				self.clients.insert(client.id, client);
+
+				(response, disconnect)
+			},
+			None => (handle_connecting_requests(&mut self.clients, request), false)
+		}
+	}
+}
+
+impl Default for Universe {
+	fn default() -> Universe {
+		Universe::new()
+	}
+}
+
+// the server with its dashboard in the terminal
+#[cfg(feature = "cli")]
 pub fn run(host: String) {
 	// channel to notify ui to update
     let (tx, rx) = mpsc::channel();
     // global state of all games and clients
-	let universe = Arc::new(Mutex::new(Universe { realms: vec![], clients: HashMap::new(), requests: vec![] }));
+	let universe = Arc::new(Mutex::new(Universe::new()));
     // getting arc of universe for this thread (ui) before moved to server thread
     let ui_glimpse = Arc::clone(&universe);
 
-    let listener = TcpListener::bind(&host).expect(&format!("could not bind tcp listener to {}", host));
+    let mut listener = transport::listen(&host).expect("could not start the server");
     // server thread
 	let _server = thread::spawn(move || {
 		// client threads
-		for stream in listener.incoming() {
-		    let mut glimpse = Arc::clone(&universe);
-		    let client_tx = tx.clone();
-			thread::spawn(move || {
-
-				let mut stream = stream.expect("could not get tcp stream.");
-				loop {
-				    let mut buffer = [0; 4096];
-
-				    stream.read(&mut buffer).expect("could not read request into buffer.");
-				    stream.flush().expect("could not flush request stream.");
-
-				    let (client_id, request): (ClientId, RealmsProtocol) = deserialize(&buffer).expect("could not deserialize client request.");
-
-				    let mut lock_glimpse = glimpse.lock().unwrap();
-
-				    // fetch current client if any
-				    let current_client: Option<Client> = lock_glimpse.clients.get_mut(&client_id).cloned();
-
-				    // seperate client and no-client request handling
-		        	if let Some(mut client) = current_client {
-		        		let response = handle_request(&mut lock_glimpse.realms, &mut client, request.clone());
-		        	    send_response(&response, &stream).expect("sending response failed.");
-
-		        		let disconnect = !client.connected;
-
-		        		// log request
-				    	lock_glimpse.requests.push((client.id, request, Local::now()));
-		        		// update client in list
-		        		lock_glimpse.clients.insert(client.id, client.clone());
-		        		
-		        	    if disconnect {
-							stream.shutdown(Shutdown::Both).expect("stream could not shut down.");
-				    		client_tx.send(Some(0)).unwrap();
-		        	    	break;
-		        	    }
-		        	} else {
-		        	    let response = handle_connecting_requests(&mut lock_glimpse.clients, request);
-		        	    send_response(&response, &stream).expect("sending response failed.");
-		        	}
-
-				    client_tx.send(Some(1)).unwrap();
+		loop {
+			// a client failing to connect does not keep the others out
+			let connection = match listener.accept() {
+				Ok(connection) => connection,
+				Err(error) => {
+					eprintln!("{}", error);
+					continue;
 				}
-			});
+			};
+		    let glimpse = Arc::clone(&universe);
+		    let client_tx = tx.clone();
+			thread::spawn(move || serve(&glimpse, connection, Some(client_tx)));
 	    }
 	});
 
@@ -124,6 +143,43 @@ pub fn run(host: String) {
 	}
 }
 
+// answers the requests coming over the connection until the client disconnects, telling the dashboard about each one
+pub fn serve(universe: &Mutex<Universe>, mut connection: Box<dyn Connection>, updates: Option<Sender<Option<usize>>>) {
+	while let Ok((client_id, request)) = connection.receive() {
+	    let (response, disconnect) = universe.lock().unwrap().handle(client_id, request);
+	    if connection.respond(&response).is_err() {
+	    	break;
+	    }
+
+	    if let Some(ref updates) = updates {
+	    	let _ = updates.send(Some(if disconnect { 0 } else { 1 }));
+	    }
+	    if disconnect {
+	    	connection.close();
+	    	break;
+	    }
+	}
+}
+
+// a universe served in the same process over channels, for tests and playing without a server
+pub struct LocalServer {
+	pub universe: Arc<Mutex<Universe>>
+}
+
+impl LocalServer {
+	pub fn start(universe: Universe) -> LocalServer {
+		LocalServer { universe: Arc::new(Mutex::new(universe)) }
+	}
+
+	// every client gets a thread of its own, just as with a server
+	pub fn connect(&self) -> ChannelTransport {
+		let (transport, connection) = transport::channel();
+		let universe = Arc::clone(&self.universe);
+		thread::spawn(move || serve(&universe, Box::new(connection), None));
+		transport
+	}
+}
+
 fn handle_connecting_requests(clients: &mut HashMap<Uuid, Client>, request: RealmsProtocol) -> RealmsProtocol {
 	match request {
 		// a connect request when the server could not find the id matching client acts as a register request.
@@ -139,8 +195,13 @@ fn handle_connecting_requests(clients: &mut HashMap<Uuid, Client>, request: Real
 	}
 }
 
-fn handle_request(realm_strategies: &mut Vec<RealmStrategy>, client: &mut Client, request: RealmsProtocol) -> RealmsProtocol {
+fn handle_request(realm_strategies: &mut BTreeMap<RealmId, RealmStrategy>, next_realm: &mut RealmId, clients: &HashMap<Uuid, Client>, client: &mut Client, request: RealmsProtocol) -> RealmsProtocol {
 	client.time = Local::now();
+	if let Some(realm_id) = request.realm() {
+		client.played.insert(realm_id, client.time);
+	}
+	// escorts go along with whoever moved while handling the request
+	let whereabouts = request.realm().and_then(|realm_id| realm_strategies.get(&realm_id)).map(|strategy| whereabouts(&strategy.realm)).unwrap_or_default();
 
 	match request {
 		RealmsProtocol::Connect(id) => {
@@ -149,107 +210,125 @@ fn handle_request(realm_strategies: &mut Vec<RealmStrategy>, client: &mut Client
     		RealmsProtocol::Connect(id)
         },
         RealmsProtocol::RequestRealmsList => {
-    		RealmsProtocol::RealmsList(client.realms_list.clone())
+    		RealmsProtocol::RealmsList(summaries(realm_strategies, client))
         },
         RealmsProtocol::RequestNewRealm => {
-        	let id = realm_strategies.len();
+        	let id = next_realm_id(next_realm);
 	        let mut strategy = RealmStrategy::new(id, RealmVariant::Tutorial);
 	        let realm = strategy.realm.clone();
 	        let realm_id = strategy.realm.id;
-    		realm_strategies.push(strategy);
+    		realm_strategies.insert(realm_id, strategy);
     		client.realms_list.insert(realm_id);
     		RealmsProtocol::Realm(realm)
         },
         RealmsProtocol::RequestRealm(realm_id) => {
-        	if realm_strategies.len() > realm_id {
-        	    if let Some(RealmStrategy {variant: _, realm, template: _}) = realm_strategies.get_mut(realm_id) {
+        	if realm_strategies.contains_key(&realm_id) {
+        	    if let Some(RealmStrategy {variant: _, realm, template: _}) = realm_strategies.get_mut(&realm_id) {
 					RealmsProtocol::Realm(realm.clone())
         	    } else {
 					RealmsProtocol::Void
         	    }
         	} else {
         		// send new realm on miss
-	        	let id = realm_strategies.len();
+	        	let id = next_realm_id(next_realm);
 		        let mut strategy = RealmStrategy::new(id, RealmVariant::Tutorial);
 		        let realm = strategy.realm.clone();
 		        let realm_id = strategy.realm.id;
-	    		realm_strategies.push(strategy);
+	    		realm_strategies.insert(realm_id, strategy);
 	    		client.realms_list.insert(realm_id);
 	    		RealmsProtocol::Realm(realm)
         	}
         },
+        RealmsProtocol::ArchiveRealm(realm_id) => {
+        	if client.archived.contains(&realm_id) {
+        		client.archived.retain(|archived| *archived != realm_id);
+        	} else if client.realms_list.iter().any(|listed| *listed == realm_id) {
+        		client.archived.push(realm_id);
+        	}
+    		RealmsProtocol::RealmsList(summaries(realm_strategies, client))
+        },
+        RealmsProtocol::DeleteRealm(realm_id) => {
+        	let listed: Vec<RealmId> = client.realms_list.iter().cloned().filter(|listed| *listed != realm_id).collect();
+        	client.realms_list = SelectionStorage::new_from(&listed);
+        	client.archived.retain(|archived| *archived != realm_id);
+        	client.played.remove(&realm_id);
+
+        	// the realm goes for good once no other client plays it
+        	let played_elsewhere = clients.values().any(|other| other.id != client.id && other.realms_list.iter().any(|listed| *listed == realm_id));
+        	if !played_elsewhere {
+        		realm_strategies.remove(&realm_id);
+        	}
+    		RealmsProtocol::RealmsList(summaries(realm_strategies, client))
+        },
+        RealmsProtocol::DuplicateRealm(realm_id) => {
+        	let id = next_realm_id(next_realm);
+        	let duplicate = realm_strategies.get(&realm_id).cloned().map(|mut strategy| {
+        		strategy.realm.id = id;
+        		strategy
+        	});
+        	if let Some(strategy) = duplicate {
+        		realm_strategies.insert(id, strategy);
+        		client.realms_list.insert(id);
+        	}
+    		RealmsProtocol::RealmsList(summaries(realm_strategies, client))
+        },
         RealmsProtocol::Explorer(Move::ChangeRegion(realm_id, region_id, explorer_id)) => {
         	// todo: check consequences of move for realm template
 
-        	let mut valid_move = false;
-        	if let Some(strategy) = realm_strategies.get(realm_id) {
-        	    valid_move = strategy.valid_move(explorer_id, region_id);
+        	match realm_strategies.get_mut(&realm_id) {
+        		Some(strategy) if strategy.valid_move(explorer_id, region_id) => relocate(strategy, client, explorer_id, region_id, &whereabouts),
+        		_ => RealmsProtocol::Rejected(format!("the explorer cannot get into region {}.", region_id))
+        	}
+        },
+        RealmsProtocol::Explorer(Move::Follow(realm_id, explorer_id, tracked_id)) => {
+        	let mut next_step = Err("the explorers are unknown.".to_string());
+        	if let Some(strategy) = realm_strategies.get(&realm_id) {
+        		let explorers = strategy.realm.expedition.explorers.storage();
+        		if let (Some(follower), Some(tracked)) = (explorers.get(explorer_id), explorers.get(tracked_id)) {
+        			next_step = tracking::next_step(follower, tracked, &strategy.template.regions, strategy.realm.age).and_then(|region_id| {
+        				if strategy.valid_move(explorer_id, region_id) {
+        					Ok(region_id)
+        				} else {
+        					Err(format!("the tracks lead into region {}, the explorer cannot get there.", region_id))
+        				}
+        			});
+        		}
         	}
 
-        	if valid_move {
-	        	if let Some(explorer) = realm_strategies.get_mut(realm_id).explorer(explorer_id) {
-	    	    	explorer.region = Some(region_id);
-	        	}
-
-	        	if let Some(strategy) = realm_strategies.get_mut(realm_id) {
-	        		let done_before = strategy.realm.done;
-	    	    	strategy.state();
-	    	    	if strategy.realm.done && !done_before {
-	    	    		client.completed_variants.push(strategy.variant.clone());
-	    	    	}
-
-					RealmsProtocol::Realm(strategy.realm.clone())
-	        	} else {
-					RealmsProtocol::Void
-	    	    }
-        	} else {
-				RealmsProtocol::Void
+        	match (next_step, realm_strategies.get_mut(&realm_id)) {
+        		(Ok(region_id), Some(strategy)) => relocate(strategy, client, explorer_id, region_id, &whereabouts),
+        		(Ok(_), None) => RealmsProtocol::Void,
+        		(Err(reason), _) => RealmsProtocol::Rejected(reason)
         	}
         },
         RealmsProtocol::Explorer(Move::Action(realm_id, region_id, explorer_id, action)) => {
         	// todo: save result of action in realm template aswell
         	// or only in template and let client side realm be filled by state update
 
-        	let mut valid_action = false;
-        	if let Some(strategy) = realm_strategies.get(realm_id) {
-        	    valid_action = strategy.valid_action(explorer_id, region_id, &action);
+        	let mut valid_action = Err("the realm is unknown.".to_string());
+        	if let Some(strategy) = realm_strategies.get(&realm_id) {
+        	    valid_action = if strategy.valid_action(explorer_id, region_id, &action) {
+        	    	Ok(())
+        	    } else {
+        	    	Err(format!("{} is not allowed in this realm.", action))
+        	    };
         	}
 
-			valid_action &= realm_strategies.get_mut(realm_id).region_explorer(region_id, explorer_id).is_some();
-			let mut region_to_update: Option<Region> = None;
-			if let Some(mut region) = realm_strategies.get_mut(realm_id).region(region_id) {
-			    if valid_action {
-    				match action {
-    				    ExplorerAction::Build => {
-    				    	region.buildings.insert("\u{2302}".to_string());
-    				    },
-    				    ExplorerAction::Map => {
-    				    	region.mapped = true;
-    				    },
-    				    ExplorerAction::Hunt => {
-    				    	if region.resources > 0 {
-    				    		region.resources -= 1;
-    				    	} else {
-    				    		valid_action = false;
-    				    	}
-    				    },
-    				    ExplorerAction::Sail => {},
-    				    ExplorerAction::Wait => {}
-    				}
-    				region_to_update = Some(region.clone());
-    	    	}
+			if valid_action.is_ok() && realm_strategies.get_mut(&realm_id).region_explorer(region_id, explorer_id).is_none() {
+				valid_action = Err("the explorer is not in the region.".to_string());
+			}
+			if let Some(RealmStrategy {variant: _, ref mut realm, ref mut template}) = realm_strategies.get_mut(&realm_id) {
+				if valid_action.is_ok() {
+					valid_action = actions::act(&action, explorer_id, region_id, &mut realm.expedition.explorers, &mut template.regions, realm.age);
+				}
 			}
 
-        	if let Some(strategy) = realm_strategies.get_mut(realm_id) {
-        		if valid_action {
-        			if let Some(region) = region_to_update {
-						strategy.template.regions.insert(region.id, region);
-        			}
-        		}
-        	}
+			if let Some(strategy) = realm_strategies.get_mut(&realm_id) {
+	        	if let Err(reason) = valid_action {
+					RealmsProtocol::Rejected(reason)
+	        	} else {
+	        		follow_escorts(strategy, &whereabouts);
 
-			if let Some(strategy) = realm_strategies.get_mut(realm_id) {
-	        	if valid_action {
 	        		let done_before = strategy.realm.done;
 	    	    	strategy.state();
 	    	    	if strategy.realm.done && !done_before {
@@ -257,9 +336,6 @@ fn handle_request(realm_strategies: &mut Vec<RealmStrategy>, client: &mut Client
 	    	    	}
 
 					RealmsProtocol::Realm(strategy.realm.clone())
-
-	        	} else {
-					RealmsProtocol::Void
 	        	}
 			} else {
 				RealmsProtocol::Void
@@ -268,16 +344,16 @@ fn handle_request(realm_strategies: &mut Vec<RealmStrategy>, client: &mut Client
         RealmsProtocol::DropEquipment(realm_id, _, explorer_id, item) => {
         	// todo: drop item in realm template aswell
 
-        	if let Some(region) = realm_strategies.get_mut(realm_id).explorer_region(explorer_id) {
+        	if let Some(region) = realm_strategies.get_mut(&realm_id).explorer_region(explorer_id) {
         	    region.particularities.insert(Particularity::Item(item));
         	}
-        	if let Some(explorer) = realm_strategies.get_mut(realm_id).explorer(explorer_id) {
+        	if let Some(explorer) = realm_strategies.get_mut(&realm_id).explorer(explorer_id) {
         	    explorer.inventory.storage_mut().iter().position(move |ref n| **n == ExplorerItem::Equipment(item)).map(|equipment| {
     				explorer.inventory.storage_mut().remove(equipment);
         		});
         	}
 
-			if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(realm_id) {
+			if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
 				RealmsProtocol::Realm(realm.clone())
 		    } else {
 		    	RealmsProtocol::Void
@@ -286,45 +362,161 @@ fn handle_request(realm_strategies: &mut Vec<RealmStrategy>, client: &mut Client
         RealmsProtocol::PickEquipment(realm_id, region_id, explorer_id, item) => {
         	// todo: pick item in realm template aswell
 
-        	if let Some(region) = realm_strategies.get_mut(realm_id).explorer_region(explorer_id) {
+        	if let Some(region) = realm_strategies.get_mut(&realm_id).explorer_region(explorer_id) {
         		region.particularities.storage_mut().iter().position(move |ref n| **n == Particularity::Item(item)).map(|equipment| {
     				region.particularities.storage_mut().remove(equipment);
         		});
         	}
-        	if let Some(explorer) = realm_strategies.get_mut(realm_id).region_explorer(region_id, explorer_id) {
+        	if let Some(explorer) = realm_strategies.get_mut(&realm_id).region_explorer(region_id, explorer_id) {
+        	    explorer.note_found(&ExplorerItem::Equipment(item));
         	    explorer.inventory.insert(ExplorerItem::Equipment(item));
         	}
 
-			if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(realm_id) {
+			if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
 				RealmsProtocol::Realm(realm.clone())
 		    } else {
 		    	RealmsProtocol::Void
 		    }
         },
         RealmsProtocol::ForgetParticularity(realm_id, region_id, explorer_id, particularity) => {
-		    if let Some(explorer) = realm_strategies.get_mut(realm_id).explorer(explorer_id) {
+		    if let Some(explorer) = realm_strategies.get_mut(&realm_id).explorer(explorer_id) {
         	    explorer.inventory.storage_mut().iter().position(move |ref n| **n == ExplorerItem::Particularity(region_id, particularity)).map(|equipment| {
     				explorer.inventory.storage_mut().remove(equipment);
         		});
         	}
         	
-		    if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(realm_id) {
+		    if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
 				RealmsProtocol::Realm(realm.clone())
 			} else {
 				RealmsProtocol::Void
     	    }
         },
         RealmsProtocol::InvestigateParticularity(realm_id, region_id, explorer_id, item) => {
-        	if let Some(explorer) = realm_strategies.get_mut(realm_id).region_explorer(region_id, explorer_id) {
-        	    explorer.inventory.insert(ExplorerItem::Particularity(region_id, item));
+        	let mut investigated = Err("the explorer is unknown.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, ref template}) = realm_strategies.get_mut(&realm_id) {
+        		if let (Some(explorer), Some(region)) = (realm.expedition.explorers.storage_mut().get_mut(explorer_id), template.regions.storage().get(&region_id)) {
+        			investigated = if explorer.region == Some(region_id) {
+        				investigation::investigate(explorer, region, item, &template.regions).map(|_| ())
+        			} else {
+        				Err("the explorer is not in the region.".to_string())
+        			};
+        		}
         	}
 
-		    if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(realm_id) {
-				RealmsProtocol::Realm(realm.clone())
-			} else {
-				RealmsProtocol::Void
+		    match (investigated, realm_strategies.get_mut(&realm_id)) {
+		    	(Err(reason), _) => RealmsProtocol::Rejected(reason),
+		    	(Ok(()), Some(RealmStrategy {variant: _, ref mut realm, template: _})) => RealmsProtocol::Realm(realm.clone()),
+		    	_ => RealmsProtocol::Void
     	    }
         },
+        RealmsProtocol::Craft(realm_id, region_id, explorer_id, recipe_id) => {
+        	let mut crafted = Err("there is no such recipe.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, ref mut template}) = realm_strategies.get_mut(&realm_id) {
+        		if let Some(recipe) = crafting::recipe(recipe_id) {
+        			if let Some(explorer) = realm.expedition.explorers.storage_mut().get_mut(explorer_id) {
+        				crafted = Err("the explorer is not in the region.".to_string());
+        				if explorer.region == Some(region_id) {
+        					if let Some(region) = template.regions.storage_mut().get_mut(&region_id) {
+        						crafted = recipe.craft(explorer, region);
+        					}
+        				}
+        			}
+        		}
+        	}
+
+        	if let Some(strategy) = realm_strategies.get_mut(&realm_id) {
+        		if let Err(reason) = crafted {
+					RealmsProtocol::Rejected(reason)
+        		} else {
+        			strategy.state();
+					RealmsProtocol::Realm(strategy.realm.clone())
+        		}
+        	} else {
+				RealmsProtocol::Void
+        	}
+        },
+        RealmsProtocol::GiveItem(realm_id, explorer_id, recipient_id, item) => {
+        	let mut given = Err("the explorers are not in the same region.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
+        		let explorers = realm.expedition.explorers.storage_mut();
+        		let together = match (explorers.get(explorer_id), explorers.get(recipient_id)) {
+        			(Some(explorer), Some(recipient)) => explorer_id != recipient_id && explorer.region.is_some() && explorer.region == recipient.region,
+        			_ => false
+        		};
+        		if together {
+        			given = Err(format!("the explorer does not carry {}.", item));
+        			if let Some(position) = explorers[explorer_id].inventory.iter().position(|carried| *carried == item) {
+        				explorers[explorer_id].inventory.storage_mut().remove(position);
+        				explorers[recipient_id].inventory.insert(item);
+        				given = Ok(());
+        			}
+        		}
+        	}
+
+		    respond(realm_strategies, realm_id, given)
+        },
+        RealmsProtocol::Talk(realm_id, explorer_id, character_id) => {
+        	let mut talking = Err("the explorer or character is unknown.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
+        		if let (Some(explorer), Some(character)) = (realm.expedition.explorers.storage().get(explorer_id), realm.characters.get_mut(character_id)) {
+        			talking = dialog::talk(character, explorer);
+        		}
+        	}
+
+		    respond(realm_strategies, realm_id, talking)
+        },
+        RealmsProtocol::Answer(realm_id, explorer_id, character_id, choice) => {
+        	let mut answered = Err("the explorer or character is unknown.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
+        		let Realm {ref mut expedition, ref mut characters, ref completed, ref mut story, ref mut escorts, ..} = *realm;
+        		if let (Some(explorer), Some(character)) = (expedition.explorers.storage_mut().get_mut(explorer_id), characters.get_mut(character_id)) {
+        			answered = dialog::answer(character, explorer, choice, completed, story, escorts);
+        		}
+        	}
+
+		    respond(realm_strategies, realm_id, answered)
+        },
+        RealmsProtocol::Report(realm_id, explorer_id, character_id, finding) => {
+        	let mut reported = Err("the explorer or character is unknown.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
+        		let Realm {ref mut expedition, ref mut characters, ref objectives, ref mut completed, ..} = *realm;
+        		if let (Some(explorer), Some(character)) = (expedition.explorers.storage_mut().get_mut(explorer_id), characters.get_mut(character_id)) {
+        			reported = investigation::report(explorer, character, finding);
+        		}
+
+        		let objective = RealmObjective::Report(finding);
+        		if reported.is_ok() && objectives.contains(&objective) && !completed.contains(&objective) {
+        			completed.push(objective);
+        		}
+        	}
+
+        	if let Some(strategy) = realm_strategies.get_mut(&realm_id) {
+        		if let Err(reason) = reported {
+					RealmsProtocol::Rejected(reason)
+        		} else {
+	        		let done_before = strategy.realm.done;
+	    	    	strategy.state();
+	    	    	if strategy.realm.done && !done_before {
+	    	    		client.completed_variants.push(strategy.variant.clone());
+	    	    	}
+
+					RealmsProtocol::Realm(strategy.realm.clone())
+        		}
+        	} else {
+				RealmsProtocol::Void
+        	}
+        },
+        RealmsProtocol::Deliver(realm_id, explorer_id, item) => {
+        	let mut delivered = Err("the explorer is unknown.".to_string());
+        	if let Some(RealmStrategy {variant: _, ref mut realm, template: _}) = realm_strategies.get_mut(&realm_id) {
+        		let Realm {ref mut expedition, ref mut characters, ..} = *realm;
+        		if let Some(explorer) = expedition.explorers.storage_mut().get_mut(explorer_id) {
+        			delivered = delivery::deliver(explorer, &item, characters);
+        		}
+        	}
+
+		    respond(realm_strategies, realm_id, delivered)
+        },
         RealmsProtocol::Quit => {
 	    	client.connected = false;
 
@@ -334,9 +526,75 @@ fn handle_request(realm_strategies: &mut Vec<RealmStrategy>, client: &mut Client
     }
 }
 
-fn send_response(data: &RealmsProtocol, mut stream: &TcpStream) -> Result<(), io::Error> {
-	let raw = serialize(data).expect("could not serialize data for response.");
-	stream.write(&raw).expect("could not write to tcp stream.");
-	stream.flush().expect("could not flush response stream.");
-	Ok(())
+// the explorer walks into the region, the way it takes is already checked
+fn relocate(strategy: &mut RealmStrategy, client: &mut Client, explorer_id: ExplorerId, region_id: RegionId, whereabouts: &[(ExplorerId, Option<RegionId>)]) -> RealmsProtocol {
+	let age = strategy.realm.age;
+	// bad weather and the dark make the way take longer
+	let ages = strategy.template.regions.storage().get(&region_id).map(|region| weather::movement(region, age)).unwrap_or(1);
+
+	if let Some(explorer) = strategy.realm.expedition.explorers.storage_mut().get_mut(explorer_id) {
+		explorer.track(region_id, age);
+	}
+	follow_escorts(strategy, whereabouts);
+
+	let done_before = strategy.realm.done;
+	for _ in 0..ages {
+		strategy.state();
+	}
+	if strategy.realm.done && !done_before {
+		client.completed_variants.push(strategy.variant.clone());
+	}
+
+	RealmsProtocol::Realm(strategy.realm.clone())
+}
+
+// where the explorers of the realm are
+fn whereabouts(realm: &Realm) -> Vec<(ExplorerId, Option<RegionId>)> {
+	realm.expedition.explorers.iter().map(|explorer| (explorer.id, explorer.region)).collect()
+}
+
+// the escorted characters keep up with every explorer no longer where it was, however it got away
+fn follow_escorts(strategy: &mut RealmStrategy, whereabouts: &[(ExplorerId, Option<RegionId>)]) {
+	for &(explorer_id, region) in whereabouts {
+		let moved = strategy.realm.expedition.explorers.storage().get(explorer_id).map(|explorer| explorer.region != region).unwrap_or(false);
+		if moved {
+			escort::follow(&mut strategy.realm, &strategy.template.regions, explorer_id);
+		}
+	}
+}
+
+fn next_realm_id(next_realm: &mut RealmId) -> RealmId {
+	let id = *next_realm;
+	*next_realm += 1;
+	id
+}
+
+// the realms of the client, archived ones last
+fn summaries(realm_strategies: &BTreeMap<RealmId, RealmStrategy>, client: &Client) -> SelectionStorage<RealmSummary> {
+	let mut summaries: Vec<RealmSummary> = client.realms_list.iter()
+		.filter_map(|realm_id| realm_strategies.get(realm_id))
+		.map(|strategy| RealmSummary {
+			id: strategy.realm.id,
+			title: strategy.realm.title.clone(),
+			variant: strategy.variant.clone(),
+			age: strategy.realm.age,
+			done: strategy.realm.done,
+			completed: strategy.realm.completed.len(),
+			objectives: strategy.realm.objectives.len(),
+			played: client.played.get(&strategy.realm.id).map(|played| played.format("%d.%m. %H:%M").to_string()),
+			seed: strategy.template.seed,
+			archived: client.archived.contains(&strategy.realm.id)
+		})
+		.collect();
+	summaries.sort_by_key(|summary| summary.archived);
+	SelectionStorage::new_from(&summaries)
+}
+
+// the realm after an order that changed nothing but the realm itself, or why the order was rejected
+fn respond(realm_strategies: &mut BTreeMap<RealmId, RealmStrategy>, realm_id: RealmId, result: Result<(), String>) -> RealmsProtocol {
+	match (result, realm_strategies.get_mut(&realm_id)) {
+		(Err(reason), _) => RealmsProtocol::Rejected(reason),
+		(Ok(()), Some(RealmStrategy {variant: _, ref mut realm, template: _})) => RealmsProtocol::Realm(realm.clone()),
+		_ => RealmsProtocol::Void
+	}
 }
@@ -1,7 +1,7 @@
 
 use realms::RealmStrategy;
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{HashMap, BTreeMap};
 use server::Client;
 use std::io;
 
@@ -17,7 +17,7 @@ use tui::style::{Style, Color};
 
 use tokens::*;
 
-pub fn draw(t: &mut Terminal<RawBackend>, requests: &Vec<(ClientId, RealmsProtocol, DateTime<Local>)>, clients: &HashMap<Uuid, Client>, realms: &Vec<RealmStrategy>) -> Result<(), io::Error> {
+pub fn draw(t: &mut Terminal<RawBackend>, requests: &[(ClientId, RealmsProtocol, DateTime<Local>)], clients: &HashMap<Uuid, Client>, realms: &BTreeMap<RealmId, RealmStrategy>) -> Result<(), io::Error> {
 	let t_size = t.size().unwrap();
 
 	Group::default()
@@ -83,7 +83,7 @@ pub fn draw(t: &mut Terminal<RawBackend>, requests: &Vec<(ClientId, RealmsProtoc
                 .render(t, &chunks[1]);
 
 
-        	let realms = realms.iter().rev().map(|RealmStrategy {variant: _, realm, template: _}| {
+        	let realms = realms.values().rev().map(|RealmStrategy {variant: _, realm, template: _}| {
         		match realm.done {
         		    true => {
         		    	Row::StyledData(
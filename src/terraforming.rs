@@ -0,0 +1,277 @@
+use std::collections::VecDeque;
+
+use tokens::*;
+use utility::*;
+
+// rivers run downhill, from the mountains to the coast
+fn elevation(region: &Region) -> usize {
+    match region.terrain {
+        Terrain::Mountain => 3,
+        Terrain::Forest => 2,
+        Terrain::Planes => 1,
+        Terrain::Coast => 0
+    }
+}
+
+fn dammed(region: &Region) -> bool {
+    region.modifications.contains(&Modification::Dam)
+}
+
+// regions connected to the start by the particularity, leaving out the excluded region.
+fn chain(regions: &SelectionHashMap<Region>, start: RegionId, particularity: Particularity, excluded: Option<RegionId>) -> Vec<RegionId> {
+    let mut chain = vec![start];
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(id) = queue.pop_front() {
+        if let Some(region) = regions.storage().get(&id) {
+            for neighbor in &region.neighbors {
+                if Some(*neighbor) == excluded || chain.contains(neighbor) {
+                    continue;
+                }
+                if regions.storage().get(neighbor).map(|neighbor| neighbor.has_particularity(particularity)).unwrap_or(false) {
+                    chain.push(*neighbor);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+    }
+
+    chain
+}
+
+// the river beyond the region, on the side it runs off to
+fn downstream(regions: &SelectionHashMap<Region>, region: RegionId) -> Vec<RegionId> {
+    let site = regions.storage()[&region].clone();
+    let mut parts: Vec<Vec<RegionId>> = vec![];
+    for neighbor in &site.neighbors {
+        let on_river = regions.storage().get(neighbor).map(|neighbor| neighbor.has_particularity(Particularity::River)).unwrap_or(false);
+        if on_river && !parts.iter().any(|part| part.contains(neighbor)) {
+            parts.push(chain(regions, *neighbor, Particularity::River, Some(region)));
+        }
+    }
+    let highest = |part: &Vec<RegionId>| part.iter().filter_map(|id| regions.storage().get(id)).map(elevation).max().unwrap_or(0);
+    let upstream = parts.iter().map(&highest).max().unwrap_or(0);
+    parts.iter()
+        .filter(|part| highest(part) < upstream || highest(part) < elevation(&site))
+        .flat_map(|part| part.iter().cloned())
+        .collect()
+}
+
+fn swap(region: &mut Region, from: Particularity, to: Particularity) {
+    if let Some(position) = region.particularities.iter().position(|particularity| *particularity == from) {
+        region.particularities.storage_mut().remove(position);
+    }
+    if !region.has_particularity(to) {
+        region.particularities.insert(to);
+    }
+}
+
+// modifications that make sense where the explorer stands
+pub fn possible(region: &Region, regions: &SelectionHashMap<Region>) -> Vec<Modification> {
+    let mut modifications = vec![];
+
+    if region.has_particularity(Particularity::River) {
+        for neighbor in region.neighbors.iter().filter_map(|neighbor| regions.storage().get(neighbor)) {
+            if !neighbor.has_particularity(Particularity::River) {
+                modifications.push(Modification::DigChannel(neighbor.id));
+            }
+        }
+    }
+    if region.has_building(Building::Dam) && !dammed(region) {
+        modifications.push(Modification::Dam);
+    }
+    if region.has_particularity(Particularity::Bolders) {
+        modifications.push(Modification::ClearBoulders);
+    }
+    if region.has_particularity(Particularity::Grove) {
+        modifications.push(Modification::FellGrove);
+    }
+
+    modifications
+}
+
+pub fn modifiable(modification: Modification, explorer: &Explorer, region: &Region, regions: &SelectionHashMap<Region>) -> Result<(), String> {
+    if explorer.region != Some(region.id) {
+        return Err("the explorer is not in the region.".to_string());
+    }
+    if !possible(region, regions).contains(&modification) {
+        return Err(format!("{} is not possible here.", modification));
+    }
+
+    match modification {
+        Modification::DigChannel(_) if !explorer.has_trait(&ExplorerTrait::Builder) => Err(format!("only an {} can dig a channel.", ExplorerTrait::Builder)),
+        Modification::DigChannel(_) | Modification::Dam | Modification::FellGrove if !explorer.has_equipment(&Equipment::Tools) => Err("that needs Tools.".to_string()),
+        Modification::ClearBoulders if !explorer.has_equipment(&Equipment::Rope) => Err("hauling boulders needs Rope.".to_string()),
+        _ => Ok(())
+    }
+}
+
+pub fn preview(modification: Modification, explorer: &Explorer, region: &Region, regions: &SelectionHashMap<Region>) -> String {
+    if let Err(reason) = modifiable(modification, explorer, region, regions) {
+        return reason;
+    }
+
+    let mut explorer = explorer.clone();
+    let mut regions = regions.clone();
+    let before: Vec<(RegionId, Vec<Particularity>)> = regions.iter().map(|(id, region)| (*id, region.particularities.storage().clone())).collect();
+    if let Err(reason) = modify(modification, &mut explorer, region.id, &mut regions) {
+        return reason;
+    }
+
+    let changed: Vec<String> = before.iter()
+        .filter(|(id, particularities)| regions.storage().get(id).map(|region| region.particularities.storage() != particularities).unwrap_or(false))
+        .map(|(id, _)| format!("{}", id))
+        .collect();
+    format!("changes regions {}.", changed.join(", "))
+}
+
+// changes the land of the region and around it, the river chain carries the change on.
+pub fn modify(modification: Modification, explorer: &mut Explorer, region: RegionId, regions: &mut SelectionHashMap<Region>) -> Result<(), String> {
+    match regions.storage().get(&region) {
+        Some(site) => modifiable(modification, explorer, site, regions)?,
+        None => return Err("the region is unknown.".to_string())
+    }
+
+    match modification {
+        Modification::DigChannel(target) => {
+            // a creek the channel runs into swells to a river all along
+            let creeks = match regions.storage().get(&target) {
+                Some(target) if target.has_particularity(Particularity::Creek) => chain(regions, target.id, Particularity::Creek, None),
+                _ => vec![target]
+            };
+            // the water takes the new way, the old one runs dry
+            for id in downstream(regions, region) {
+                if let Some(region) = regions.storage_mut().get_mut(&id) {
+                    swap(region, Particularity::River, Particularity::Creek);
+                }
+            }
+            for id in creeks {
+                if let Some(region) = regions.storage_mut().get_mut(&id) {
+                    swap(region, Particularity::Creek, Particularity::River);
+                }
+            }
+        },
+        Modification::Dam => {
+            // the river below the dam runs dry, what lies upstream keeps its water
+            let below = downstream(regions, region);
+            for id in below {
+                if let Some(region) = regions.storage_mut().get_mut(&id) {
+                    swap(region, Particularity::River, Particularity::Creek);
+                }
+            }
+            if let Some(site) = regions.storage_mut().get_mut(&region) {
+                if !site.has_particularity(Particularity::Lake) {
+                    site.particularities.insert(Particularity::Lake);
+                }
+            }
+        },
+        Modification::ClearBoulders => {
+            // without the boulders water finds its way into the canyon
+            let water = regions.storage()[&region].neighbors.iter()
+                .any(|neighbor| regions.storage().get(neighbor).map(|neighbor| neighbor.has_particularity(Particularity::River)).unwrap_or(false));
+            if let Some(site) = regions.storage_mut().get_mut(&region) {
+                if let Some(position) = site.particularities.iter().position(|particularity| *particularity == Particularity::Bolders) {
+                    site.particularities.storage_mut().remove(position);
+                }
+                if water && site.has_particularity(Particularity::Canyon) {
+                    site.particularities.insert(Particularity::River);
+                }
+            }
+        },
+        Modification::FellGrove => {
+            // the grove held the water of the creeks around
+            let neighbors = regions.storage()[&region].neighbors.clone();
+            for neighbor in neighbors {
+                if let Some(neighbor) = regions.storage_mut().get_mut(&neighbor) {
+                    if let Some(position) = neighbor.particularities.iter().position(|particularity| *particularity == Particularity::Creek) {
+                        neighbor.particularities.storage_mut().remove(position);
+                    }
+                }
+            }
+            if let Some(site) = regions.storage_mut().get_mut(&region) {
+                swap(site, Particularity::Grove, Particularity::Clearing);
+            }
+            explorer.add_equipment(Equipment::Firewood(3));
+        }
+    }
+
+    if let Some(site) = regions.storage_mut().get_mut(&region) {
+        site.modifications.push(modification);
+    }
+    explorer.note_action(&ExplorerAction::Modify(modification));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixtures::*;
+
+    // a river runs from the mountain (1) over the forest (2) and the planes (3) to the coast (4),
+    // a creek from the planes (5) by the forest to the coast (6).
+    fn valley() -> SelectionHashMap<Region> {
+        regions(vec![
+            region(1, Terrain::Mountain, vec![Particularity::River], vec![2]),
+            region(2, Terrain::Forest, vec![Particularity::River], vec![1, 3, 5]),
+            region(3, Terrain::Planes, vec![Particularity::River], vec![2, 4]),
+            region(4, Terrain::Coast, vec![Particularity::River], vec![3]),
+            region(5, Terrain::Planes, vec![Particularity::Creek], vec![2, 6]),
+            region(6, Terrain::Coast, vec![Particularity::Creek], vec![5])
+        ])
+    }
+
+    fn river(regions: &SelectionHashMap<Region>) -> Vec<RegionId> {
+        regions.iter().filter(|(_, region)| region.has_particularity(Particularity::River)).map(|(id, _)| *id).collect()
+    }
+
+    fn creek(regions: &SelectionHashMap<Region>) -> Vec<RegionId> {
+        regions.iter().filter(|(_, region)| region.has_particularity(Particularity::Creek)).map(|(id, _)| *id).collect()
+    }
+
+    #[test]
+    fn a_channel_leads_the_river_into_the_creek() {
+        let mut regions = valley();
+        let mut builder = explorer(1, vec![ExplorerTrait::Builder], Some(2), vec![Equipment::Tools]);
+
+        modify(Modification::DigChannel(5), &mut builder, 2, &mut regions).unwrap();
+
+        // the old downstream runs dry, the creek swells to a river all along
+        assert_eq!(river(&regions), vec![1, 2, 5, 6]);
+        assert_eq!(creek(&regions), vec![3, 4]);
+        assert_eq!(regions.storage()[&2].modifications, vec![Modification::DigChannel(5)]);
+    }
+
+    #[test]
+    fn only_an_engineer_digs_a_channel() {
+        let mut regions = valley();
+        let mut ranger = explorer(1, vec![ExplorerTrait::Ranger], Some(2), vec![Equipment::Tools]);
+
+        assert_eq!(modify(Modification::DigChannel(5), &mut ranger, 2, &mut regions), Err("only an Engineer can dig a channel.".to_string()));
+        assert_eq!(river(&regions), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn a_dam_leaves_the_river_below_dry() {
+        let mut regions = valley();
+        regions.storage_mut().get_mut(&2).unwrap().buildings.insert(Construction { building: Building::Dam, progress: Building::Dam.turns() });
+        let mut builder = explorer(1, vec![], Some(2), vec![Equipment::Tools]);
+
+        modify(Modification::Dam, &mut builder, 2, &mut regions).unwrap();
+
+        assert_eq!(river(&regions), vec![1, 2]);
+        assert_eq!(creek(&regions), vec![3, 4, 5, 6]);
+        assert!(regions.storage()[&2].has_particularity(Particularity::Lake));
+        assert!(!possible(&regions.storage()[&2], &regions).contains(&Modification::Dam));
+    }
+
+    #[test]
+    fn an_unfinished_dam_can_not_be_closed() {
+        let mut regions = valley();
+        regions.storage_mut().get_mut(&2).unwrap().buildings.insert(Construction { building: Building::Dam, progress: 0 });
+        let mut builder = explorer(1, vec![], Some(2), vec![Equipment::Tools]);
+
+        assert!(modify(Modification::Dam, &mut builder, 2, &mut regions).is_err());
+        assert_eq!(river(&regions), vec![1, 2, 3, 4]);
+    }
+}
@@ -1,8 +1,9 @@
 
-use realms::RealmStrategy;
+use realms::{RealmStrategy, RealmVariant};
 use utility::*;
 use std::fmt;
 use std::cmp;
+use std::mem;
 use std::hash::{Hash, Hasher};
 
 use uuid::Uuid;
@@ -11,21 +12,37 @@ pub type ClientId = Uuid;
 pub type RealmId = usize;
 pub type RegionId = usize;
 pub type ExplorerId = usize;
+pub type RecipeId = usize;
+pub type CharacterId = usize;
+pub type DialogNodeId = usize;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum RealmsProtocol {
     Register,
     Connect(ClientId),
     RequestRealmsList,
-    RealmsList(SelectionStorage<RealmId>),
+    RealmsList(SelectionStorage<RealmSummary>),
     RequestNewRealm,
     RequestRealm(RealmId),
+    // archiving again brings the realm back, all three answer with the realms list
+    ArchiveRealm(RealmId),
+    DeleteRealm(RealmId),
+    DuplicateRealm(RealmId),
     Realm(Realm),
     Explorer(Move),
     DropEquipment(RealmId, RegionId, ExplorerId, Equipment),
     PickEquipment(RealmId, RegionId, ExplorerId, Equipment),
     InvestigateParticularity(RealmId, RegionId, ExplorerId, Particularity),
     ForgetParticularity(RealmId, RegionId, ExplorerId, Particularity),
+    Craft(RealmId, RegionId, ExplorerId, RecipeId),
+    GiveItem(RealmId, ExplorerId, ExplorerId, ExplorerItem),
+    Talk(RealmId, ExplorerId, CharacterId),
+    // picks a choice by its index in the current dialog node
+    Answer(RealmId, ExplorerId, CharacterId, usize),
+    Report(RealmId, ExplorerId, CharacterId, Finding),
+    Deliver(RealmId, ExplorerId, Delivery),
+    // an order the server turned down, with the reason why
+    Rejected(String),
     Quit,
     Void
 }
@@ -33,6 +50,8 @@ pub enum RealmsProtocol {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Move {
     ChangeRegion(RealmId, RegionId, ExplorerId),
+    // a ranger following the tracks of another explorer
+    Follow(RealmId, ExplorerId, ExplorerId),
     Action(RealmId, RegionId, ExplorerId, ExplorerAction)
 }
 
@@ -45,10 +64,57 @@ impl fmt::Display for RealmsProtocol {
     }
 }
 
+impl RealmsProtocol {
+    // the realm a request is played in
+    pub fn realm(&self) -> Option<RealmId> {
+        match self {
+            RealmsProtocol::RequestRealm(realm)
+            | RealmsProtocol::Explorer(Move::ChangeRegion(realm, _, _))
+            | RealmsProtocol::Explorer(Move::Follow(realm, _, _))
+            | RealmsProtocol::Explorer(Move::Action(realm, _, _, _))
+            | RealmsProtocol::DropEquipment(realm, _, _, _)
+            | RealmsProtocol::PickEquipment(realm, _, _, _)
+            | RealmsProtocol::InvestigateParticularity(realm, _, _, _)
+            | RealmsProtocol::ForgetParticularity(realm, _, _, _)
+            | RealmsProtocol::Craft(realm, _, _, _)
+            | RealmsProtocol::GiveItem(realm, _, _, _)
+            | RealmsProtocol::Talk(realm, _, _)
+            | RealmsProtocol::Answer(realm, _, _, _)
+            | RealmsProtocol::Report(realm, _, _, _)
+            | RealmsProtocol::Deliver(realm, _, _) => Some(*realm),
+            _ => None
+        }
+    }
+}
+
+// what the realms list tells about a realm without sending all of it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RealmSummary {
+    pub id: RealmId,
+    pub title: String,
+    pub variant: RealmVariant,
+    pub age: usize,
+    pub done: bool,
+    pub completed: usize,
+    pub objectives: usize,
+    // when the client last sent an order to the realm, if ever
+    pub played: Option<String>,
+    pub seed: u64,
+    pub archived: bool
+}
+
+impl fmt::Display for RealmSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.id, self.title)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Realm {
     pub island: Island,
     pub expedition: Expedition,
+    pub characters: Vec<Character>,
+    pub escorts: Vec<Escort>,
     pub id: RealmId,
     pub age: usize,
     pub title: String,
@@ -63,6 +129,8 @@ impl Realm {
         Realm {
             island: Island::new(),
             expedition: Expedition::new(),
+            characters: vec![],
+            escorts: vec![],
             id,
             age: 0,
             title: "a realm".to_string(),
@@ -141,13 +209,19 @@ impl<'a> LazyRealmAccess<'a> for Option<&'a mut RealmStrategy> {
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum RealmObjective {
-    EmbarkExplorers
+    EmbarkExplorers,
+    BuildWell,
+    Report(Finding),
+    ClaimRegions(usize)
 }
 
 impl fmt::Display for RealmObjective {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            RealmObjective::EmbarkExplorers => write!(f, "embark all explorers.")
+            RealmObjective::EmbarkExplorers => write!(f, "embark all explorers."),
+            RealmObjective::BuildWell => write!(f, "build a well for the farmers."),
+            RealmObjective::Report(finding) => write!(f, "report {}.", finding),
+            RealmObjective::ClaimRegions(regions) => write!(f, "claim {} regions for the expedition.", regions)
         }
     }
 }
@@ -165,22 +239,52 @@ impl Island {
     }
 }
 
+impl Default for Island {
+    fn default() -> Island {
+        Island::new()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Region {
     pub id: RegionId,
     pub terrain: Terrain,
     pub particularities: SelectionStorage<Particularity>,
-    pub buildings: SelectionStorage<String>,
-    pub mapped: bool,
+    pub buildings: SelectionStorage<Construction>,
+    pub mapped: Option<Survey>,
     pub resources: usize,
     pub sight: RegionVisibility,
+    // realm age the expedition last laid eyes on the region
+    pub seen: Option<usize>,
+    pub owner: Option<Faction>,
+    pub blockade: Option<Blockade>,
+    // what explorers did to the land, oldest first
+    pub modifications: Vec<Modification>,
+    // progress towards the next resource growing back
+    pub growth: usize,
+    // how badly the region was overhunted, slows down regrowth
+    pub depletion: usize,
+    pub weather: Weather,
     pub neighbors: Vec<RegionId>,
     pub hex_offset_coords: (usize, usize)
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl Region {
+    // only completed buildings count
+    pub fn has_building(&self, building: Building) -> bool {
+        self.buildings.iter().any(|construction| construction.building == building && construction.complete())
+    }
+
+    pub fn has_particularity(&self, particularity: Particularity) -> bool {
+        self.particularities.iter().any(|present| *present == particularity)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
 pub enum RegionVisibility {
     None,
+    Remembered,
+    Mapped,
     Partial,
     Complete,
     Live
@@ -218,7 +322,16 @@ impl fmt::Display for Region {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+// a region as the cartographer found it
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Survey {
+    pub region: RegionId,
+    pub terrain: Terrain,
+    pub particularities: Vec<Particularity>,
+    pub age: usize
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum Terrain {
     Coast,
     Planes,
@@ -252,15 +365,153 @@ pub enum Particularity {
     Castle,
     Fortress,
     Haven,
-    Character
+    Character,
+    Campfire
     // todo add more for plot and story
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Building {
+    Camp,
+    Well,
+    Bridge,
+    Lighthouse,
+    Dam,
+    Watchtower
+}
+
+impl Building {
+    pub fn all() -> Vec<Building> {
+        vec![
+            Building::Camp,
+            Building::Well,
+            Building::Bridge,
+            Building::Lighthouse,
+            Building::Dam,
+            Building::Watchtower
+        ]
+    }
+
+    // materials consumed from the builder's inventory when construction starts
+    pub fn costs(&self) -> Vec<Equipment> {
+        match self {
+            Building::Camp => vec![Equipment::Blankets, Equipment::Firewood(1)],
+            Building::Well => vec![Equipment::Rope, Equipment::Firewood(2)],
+            Building::Bridge => vec![Equipment::Rope, Equipment::Firewood(3)],
+            Building::Lighthouse => vec![Equipment::Coal(2), Equipment::Firewood(2)],
+            Building::Dam => vec![Equipment::Firewood(4)],
+            Building::Watchtower => vec![Equipment::Rope, Equipment::Firewood(2)]
+        }
+    }
+
+    // build actions needed until the building is complete
+    pub fn turns(&self) -> usize {
+        match self {
+            Building::Camp => 1,
+            Building::Well => 3,
+            Building::Bridge => 2,
+            Building::Lighthouse => 4,
+            Building::Dam => 3,
+            Building::Watchtower => 2
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Construction {
+    pub building: Building,
+    pub progress: usize
+}
+
+impl Construction {
+    pub fn complete(&self) -> bool {
+        self.progress >= self.building.turns()
+    }
+}
+
+impl fmt::Display for Construction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.complete() {
+            write!(f, "\u{2302} {:?}", self.building)
+        } else {
+            write!(f, "{:?} {}/{}", self.building, self.progress, self.building.turns())
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Character {
-    name: String,
-    text: String,
-    dialog: Vec<String>
+    pub id: CharacterId,
+    pub name: String,
+    pub text: String,
+    pub region: RegionId,
+    // the tag marking the character among the particularities of its region
+    pub particularity: Particularity,
+    pub dialog: Vec<DialogNode>,
+    pub affection: isize,
+    // findings the character wants to hear about
+    pub interests: Vec<Finding>,
+    // deliveries the character hands out, one at a time
+    pub errands: Vec<Delivery>,
+    // the explorer talking to the character and where they are in the dialog
+    pub conversation: Option<(ExplorerId, DialogNodeId)>
+}
+
+// the first node of a dialog is where every conversation starts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DialogNode {
+    pub id: DialogNodeId,
+    pub text: String,
+    pub choices: Vec<DialogChoice>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DialogChoice {
+    pub text: String,
+    pub conditions: Vec<DialogCondition>,
+    pub effects: Vec<DialogEffect>,
+    // the conversation ends without a next node
+    pub next: Option<DialogNodeId>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum DialogCondition {
+    Carries(Equipment),
+    Completed(RealmObjective),
+    AffectionAtLeast(isize),
+    AffectionBelow(isize),
+    HasErrand
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum DialogEffect {
+    GiveItem(ExplorerItem),
+    TakeEquipment(Equipment),
+    Affection(isize),
+    Story(String),
+    // hands out the next errand of the character
+    Errand,
+    // the character asks the explorer to take it to a region
+    Escort(RegionId)
+}
+
+// a character following an explorer region by region
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Escort {
+    pub character: CharacterId,
+    pub explorer: ExplorerId,
+    pub destination: RegionId,
+    pub state: EscortState,
+    // what happened on the way
+    pub log: Vec<String>
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum EscortState {
+    Underway,
+    // lost the explorer and waits to be found again
+    Disoriented,
+    SafeAndSound
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -276,12 +527,28 @@ impl Expedition {
     }
 }
 
+impl Default for Expedition {
+    fn default() -> Expedition {
+        Expedition::new()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Explorer {
     pub id: ExplorerId,
     pub traits: SelectionStorage<ExplorerTrait>,
     pub region: Option<RegionId>,
-    pub inventory: SelectionStorage<ExplorerItem>
+    pub inventory: SelectionStorage<ExplorerItem>,
+    pub trail: Vec<TrailMark>
+}
+
+// a visit of an explorer to a region
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TrailMark {
+    pub region: RegionId,
+    pub age: usize,
+    pub actions: Vec<ExplorerAction>,
+    pub found: Vec<ExplorerItem>
 }
 
 impl Explorer {
@@ -292,13 +559,93 @@ impl Explorer {
                 match explorer_trait {
                     ExplorerTrait::Ranger => actions.push(ExplorerAction::Hunt),
                     ExplorerTrait::Cartographer => actions.push(ExplorerAction::Map),
-                    ExplorerTrait::Builder => actions.push(ExplorerAction::Build),
-                    ExplorerTrait::Sailor => actions.push(ExplorerAction::Sail)
+                    ExplorerTrait::Builder => {
+                        for building in Building::all() {
+                            actions.push(ExplorerAction::Build(building));
+                        }
+                    },
+                    // destinations depend on the coast, see sailing::destinations
+                    ExplorerTrait::Sailor => {}
                 }
             }
         }
         actions
     }
+
+    // moves the explorer and leaves a mark on the trail
+    pub fn track(&mut self, region: RegionId, age: usize) {
+        self.region = Some(region);
+        self.trail.push(TrailMark { region, age, actions: vec![], found: vec![] });
+    }
+
+    pub fn note_action(&mut self, action: &ExplorerAction) {
+        if let Some(mark) = self.trail.last_mut() {
+            mark.actions.push(action.clone());
+        }
+    }
+
+    pub fn note_found(&mut self, item: &ExplorerItem) {
+        if let Some(mark) = self.trail.last_mut() {
+            mark.found.push(item.clone());
+        }
+    }
+
+    pub fn has_trait(&self, explorer_trait: &ExplorerTrait) -> bool {
+        self.traits.iter().any(|carried| carried == explorer_trait)
+    }
+
+    pub fn has_equipment(&self, equipment: &Equipment) -> bool {
+        self.inventory.iter().any(|item| {
+            match item {
+                ExplorerItem::Equipment(carried) => carried.same_kind(equipment) && carried.amount() >= equipment.amount(),
+                _ => false
+            }
+        })
+    }
+
+    // removes the equipment, or the given amount of it when it comes in stacks.
+    pub fn take_equipment(&mut self, equipment: &Equipment) -> bool {
+        let position = self.inventory.iter().position(|item| {
+            match item {
+                ExplorerItem::Equipment(carried) => carried.same_kind(equipment) && carried.amount() >= equipment.amount(),
+                _ => false
+            }
+        });
+
+        match position {
+            Some(index) => {
+                let mut remainder = None;
+                if let ExplorerItem::Equipment(carried) = self.inventory.storage()[index] {
+                    if let (Some(carried_amount), Some(amount)) = (carried.amount(), equipment.amount()) {
+                        if carried_amount > amount {
+                            remainder = Some(carried.with_amount(carried_amount - amount));
+                        }
+                    }
+                }
+                match remainder {
+                    Some(carried) => self.inventory.storage_mut()[index] = ExplorerItem::Equipment(carried),
+                    None => { self.inventory.storage_mut().remove(index); }
+                }
+                true
+            },
+            None => false
+        }
+    }
+
+    // adds the equipment, stacking it onto what the explorer already carries.
+    pub fn add_equipment(&mut self, equipment: Equipment) {
+        for item in self.inventory.iter_mut() {
+            if let ExplorerItem::Equipment(carried) = item {
+                if carried.same_kind(&equipment) {
+                    if let (Some(carried_amount), Some(amount)) = (carried.amount(), equipment.amount()) {
+                        *carried = carried.with_amount(carried_amount + amount);
+                        return;
+                    }
+                }
+            }
+        }
+        self.inventory.insert(ExplorerItem::Equipment(equipment));
+    }
 }
 
 impl fmt::Display for Explorer {
@@ -309,13 +656,97 @@ impl fmt::Display for Explorer {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ExplorerAction {
-    Build,
+    Build(Building),
     Hunt,
-    Sail,
+    Gather(Equipment),
+    Sail(RegionId),
     Map,
+    Negotiate(RegionId),
+    Dismantle(RegionId),
+    Bypass(RegionId),
+    Claim,
+    Modify(Modification),
     Wait
 }
 
+impl fmt::Display for ExplorerAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExplorerAction::Build(building) => write!(f, "Build {:?}", building),
+            ExplorerAction::Gather(equipment) => write!(f, "Gather {:?}", equipment),
+            ExplorerAction::Sail(region) => write!(f, "Sail to {}", region),
+            ExplorerAction::Negotiate(region) => write!(f, "Negotiate blockade at {}", region),
+            ExplorerAction::Dismantle(region) => write!(f, "Dismantle blockade at {}", region),
+            ExplorerAction::Bypass(region) => write!(f, "Bypass blockade into {}", region),
+            ExplorerAction::Modify(modification) => write!(f, "{}", modification),
+            action => write!(f, "{:?}", action)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Modification {
+    // leads the river of the region into a neighboring one
+    DigChannel(RegionId),
+    // closes a finished dam, leaving the river below dry
+    Dam,
+    ClearBoulders,
+    FellGrove
+}
+
+impl fmt::Display for Modification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Modification::DigChannel(region) => write!(f, "Dig channel to {}", region),
+            Modification::Dam => write!(f, "Close dam"),
+            Modification::ClearBoulders => write!(f, "Clear boulders"),
+            Modification::FellGrove => write!(f, "Fell grove")
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Daytime {
+    Morning,
+    Noon,
+    Evening,
+    Night
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Weather {
+    Clear,
+    Rain,
+    Fog,
+    Storm
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Faction {
+    Expedition,
+    Crown,
+    Farmers,
+    Bandits
+}
+
+// keeps anyone from walking into a region
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Blockade {
+    pub faction: Faction,
+    // how much work dismantling it takes
+    pub strength: usize,
+    // what it takes to be let through
+    pub toll: Equipment
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum ExplorerTrait {
     Ranger,
@@ -364,9 +795,134 @@ pub enum Equipment {
     Raft
 }
 
+impl Equipment {
+    pub fn amount(&self) -> Option<usize> {
+        match self {
+            Equipment::Firewood(amount)
+            | Equipment::Coal(amount)
+            | Equipment::Gold(amount)
+            | Equipment::Coins(amount)
+            | Equipment::Herbs(amount)
+            | Equipment::Food(amount)
+            | Equipment::Parchment(amount)
+            | Equipment::Arrows(amount) => Some(*amount),
+            _ => None
+        }
+    }
+
+    pub fn with_amount(&self, amount: usize) -> Equipment {
+        match self {
+            Equipment::Firewood(_) => Equipment::Firewood(amount),
+            Equipment::Coal(_) => Equipment::Coal(amount),
+            Equipment::Gold(_) => Equipment::Gold(amount),
+            Equipment::Coins(_) => Equipment::Coins(amount),
+            Equipment::Herbs(_) => Equipment::Herbs(amount),
+            Equipment::Food(_) => Equipment::Food(amount),
+            Equipment::Parchment(_) => Equipment::Parchment(amount),
+            Equipment::Arrows(_) => Equipment::Arrows(amount),
+            equipment => *equipment
+        }
+    }
+
+    // equipment of the same kind regardless of the amount, e.g. `Firewood(1)` and `Firewood(4)`.
+    pub fn same_kind(&self, other: &Equipment) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum ExplorerItem {
     Equipment(Equipment),
     Particularity(RegionId, Particularity),
-    Message(String)
+    Message(String),
+    Map(Vec<Survey>),
+    Finding(Finding),
+    Delivery(Delivery)
+}
+
+impl fmt::Display for ExplorerItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExplorerItem::Equipment(equipment) => write!(f, "{:?}", equipment),
+            ExplorerItem::Particularity(region, particularity) => write!(f, "{:?} at {}", particularity, region),
+            ExplorerItem::Message(message) => write!(f, "Message: {}", message),
+            ExplorerItem::Map(surveys) => {
+                let regions: Vec<String> = surveys.iter().map(|survey| format!("{}", survey.region)).collect();
+                write!(f, "Map of {}", regions.join(", "))
+            },
+            ExplorerItem::Finding(finding) => write!(f, "Finding: {}", finding),
+            ExplorerItem::Delivery(delivery) => write!(f, "{}", delivery)
+        }
+    }
+}
+
+// what a combination of clues adds up to
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Finding {
+    RiverSource,
+    TradeRoute,
+    Shipwreck,
+    HiddenValley
+}
+
+impl Finding {
+    pub fn all() -> Vec<Finding> {
+        vec![Finding::RiverSource, Finding::TradeRoute, Finding::Shipwreck, Finding::HiddenValley]
+    }
+
+    pub fn clues(&self) -> Vec<Particularity> {
+        match self {
+            Finding::RiverSource => vec![Particularity::River, Particularity::Creek, Particularity::Canyon],
+            Finding::TradeRoute => vec![Particularity::Town, Particularity::Carravan, Particularity::Merchant],
+            Finding::Shipwreck => vec![Particularity::Ship, Particularity::Cliffs, Particularity::Island],
+            Finding::HiddenValley => vec![Particularity::Grasland, Particularity::Clearing, Particularity::Lake]
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Finding::RiverSource => write!(f, "the source of the river"),
+            Finding::TradeRoute => write!(f, "an old trade route"),
+            Finding::Shipwreck => write!(f, "a shipwreck"),
+            Finding::HiddenValley => write!(f, "a hidden valley")
+        }
+    }
+}
+
+// something to bring somewhere on behalf of a character
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Delivery {
+    pub cargo: Cargo,
+    pub destination: Destination,
+    pub client: CharacterId,
+    pub reward: Vec<Equipment>
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Cargo {
+    Message(String),
+    Goods(Equipment),
+    Person(String)
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Destination {
+    Region(RegionId),
+    Character(CharacterId, String)
+}
+
+impl fmt::Display for Delivery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let destination = match self.destination {
+            Destination::Region(region) => format!("region {}", region),
+            Destination::Character(_, ref name) => name.clone()
+        };
+        match self.cargo {
+            Cargo::Message(ref message) => write!(f, "Message for {}: {}", destination, message),
+            Cargo::Goods(goods) => write!(f, "{:?} for {}", goods, destination),
+            Cargo::Person(ref person) => write!(f, "Escort {} to {}", person, destination)
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,121 @@
+use tokens::*;
+use utility::*;
+
+// realm ages after which tracks are washed away
+pub const TRACKS_FADE: usize = 30;
+
+// marks of the trail that can still be made out
+pub fn visible_tracks(explorer: &Explorer, age: usize) -> Vec<&TrailMark> {
+    explorer.trail.iter()
+        .filter(|mark| mark.age + TRACKS_FADE >= age)
+        .collect()
+}
+
+// explorers that left tracks in a region that can still be made out
+pub fn tracks_in(region: RegionId, explorers: &SelectionStorage<Explorer>, age: usize) -> Vec<ExplorerId> {
+    explorers.iter()
+        .filter(|explorer| visible_tracks(explorer, age).iter().any(|mark| mark.region == region))
+        .map(|explorer| explorer.id)
+        .collect()
+}
+
+pub fn trackable(follower: &Explorer, tracked: &Explorer) -> Result<(), String> {
+    if !follower.has_trait(&ExplorerTrait::Ranger) {
+        return Err(format!("only a {} can follow tracks.", ExplorerTrait::Ranger));
+    }
+    if follower.id == tracked.id {
+        return Err("an explorer can not follow its own tracks.".to_string());
+    }
+    if follower.region.is_none() {
+        return Err("the explorer has not embarked yet.".to_string());
+    }
+    if follower.region == tracked.region {
+        return Err(format!("explorer {} is right here.", tracked.id));
+    }
+    Ok(())
+}
+
+// the region the tracks lead to from where the follower stands, picking up the latest visit.
+pub fn next_step(follower: &Explorer, tracked: &Explorer, regions: &SelectionHashMap<Region>, age: usize) -> Result<RegionId, String> {
+    trackable(follower, tracked)?;
+
+    let tracks = visible_tracks(tracked, age);
+    let here = match follower.region.and_then(|region| regions.storage().get(&region)) {
+        Some(region) => region,
+        None => return Err("the explorer has not embarked yet.".to_string())
+    };
+
+    match tracks.iter().rposition(|mark| mark.region == here.id) {
+        Some(index) if index + 1 < tracks.len() => {
+            let next = tracks[index + 1].region;
+            if here.neighbors.contains(&next) {
+                Ok(next)
+            } else {
+                Err("the tracks end at the water.".to_string())
+            }
+        },
+        Some(_) => Err("the tracks end here.".to_string()),
+        None => Err(format!("there are no tracks of explorer {} here.", tracked.id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixtures::*;
+
+    // a path from 1 over 2 to 3, the island 4 is only reached by sea
+    fn path() -> SelectionHashMap<Region> {
+        regions(vec![
+            region(1, Terrain::Coast, vec![], vec![2]),
+            region(2, Terrain::Forest, vec![], vec![1, 3]),
+            region(3, Terrain::Coast, vec![], vec![2]),
+            region(4, Terrain::Coast, vec![Particularity::Island], vec![])
+        ])
+    }
+
+    fn walker(steps: &[RegionId]) -> Explorer {
+        let mut walker = explorer(2, vec![], None, vec![]);
+        for (age, region) in steps.iter().enumerate() {
+            walker.track(*region, age);
+        }
+        walker
+    }
+
+    #[test]
+    fn the_tracks_lead_on_from_the_latest_visit() {
+        let regions = path();
+        let tracked = walker(&[1, 2, 3, 2, 1, 2]);
+        let ranger = explorer(1, vec![ExplorerTrait::Ranger], Some(1), vec![]);
+        assert_eq!(next_step(&ranger, &tracked, &regions, 6), Ok(2));
+
+        let tracked = walker(&[1, 2, 3, 2, 1, 2, 3]);
+        let ranger = explorer(1, vec![ExplorerTrait::Ranger], Some(2), vec![]);
+        assert_eq!(next_step(&ranger, &tracked, &regions, 7), Ok(3));
+    }
+
+    #[test]
+    fn the_tracks_end_where_the_explorer_set_sail() {
+        let regions = path();
+        let tracked = walker(&[1, 2, 3, 4]);
+        let ranger = explorer(1, vec![ExplorerTrait::Ranger], Some(3), vec![]);
+        assert_eq!(next_step(&ranger, &tracked, &regions, 4), Err("the tracks end at the water.".to_string()));
+    }
+
+    #[test]
+    fn tracks_fade_with_time() {
+        let regions = path();
+        let tracked = walker(&[1, 2, 3]);
+        let ranger = explorer(1, vec![ExplorerTrait::Ranger], Some(1), vec![]);
+        assert_eq!(next_step(&ranger, &tracked, &regions, TRACKS_FADE), Ok(2));
+        assert!(next_step(&ranger, &tracked, &regions, TRACKS_FADE + 1).is_err());
+    }
+
+    #[test]
+    fn only_a_ranger_follows_tracks() {
+        let regions = path();
+        let tracked = walker(&[1, 2, 3]);
+        let builder = explorer(1, vec![ExplorerTrait::Builder], Some(1), vec![]);
+        assert!(next_step(&builder, &tracked, &regions, 3).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::net::{TcpStream, TcpListener, Shutdown};
+use std::os::unix::net::{UnixStream, UnixListener};
+use std::os::unix::fs::FileTypeExt;
+use std::io::prelude::*;
+use std::sync::mpsc::{self, Sender, Receiver};
+use std::fs;
+
+use bincode::{serialize_into, deserialize_from};
+
+use tokens::*;
+
+// hosts starting with it name a socket file instead of an address
+pub const UNIX_PREFIX: &str = "unix:";
+
+// the client's end, carries its requests to a universe and brings back the answers
+pub trait Transport {
+	fn exchange(&mut self, client: ClientId, request: &RealmsProtocol) -> Result<RealmsProtocol, String>;
+
+	// called once the client quit
+	fn close(&mut self) { }
+}
+
+// the server's end, one per client
+pub trait Connection: Send {
+	fn receive(&mut self) -> Result<(ClientId, RealmsProtocol), String>;
+
+	fn respond(&mut self, response: &RealmsProtocol) -> Result<(), String>;
+
+	// called once the client disconnected
+	fn close(&mut self) { }
+}
+
+// hands the server a connection for every client turning up
+pub trait Listener: Send {
+	fn accept(&mut self) -> Result<Box<dyn Connection>, String>;
+}
+
+// byte streams carry the protocol the same way whatever is underneath
+pub trait Stream: Read + Write + Send {
+	fn shutdown(&self);
+}
+
+impl Stream for TcpStream {
+	fn shutdown(&self) {
+		let _ = TcpStream::shutdown(self, Shutdown::Both);
+	}
+}
+
+impl Stream for UnixStream {
+	fn shutdown(&self) {
+		let _ = UnixStream::shutdown(self, Shutdown::Both);
+	}
+}
+
+// either end of a byte stream, requests are sent along with the id of the client
+pub struct StreamTransport<S: Stream> {
+	stream: S
+}
+
+pub type TcpTransport = StreamTransport<TcpStream>;
+pub type UnixTransport = StreamTransport<UnixStream>;
+
+impl<S: Stream> StreamTransport<S> {
+	pub fn new(stream: S) -> StreamTransport<S> {
+		StreamTransport { stream }
+	}
+
+	fn send<T: ::serde::Serialize>(&mut self, data: &T) -> Result<(), String> {
+		serialize_into(&mut self.stream, data).map_err(|error| format!("could not send: {}", error))?;
+		self.stream.flush().map_err(|error| format!("could not send: {}", error))
+	}
+}
+
+impl<S: Stream> Transport for StreamTransport<S> {
+	fn exchange(&mut self, client: ClientId, request: &RealmsProtocol) -> Result<RealmsProtocol, String> {
+		self.send(&(client, request))?;
+		deserialize_from(&mut self.stream).map_err(|error| format!("could not read the answer: {}", error))
+	}
+
+	fn close(&mut self) {
+		self.stream.shutdown();
+	}
+}
+
+impl<S: Stream> Connection for StreamTransport<S> {
+	fn receive(&mut self) -> Result<(ClientId, RealmsProtocol), String> {
+		// requests are read straight from the stream, regardless of their size
+		deserialize_from(&mut self.stream).map_err(|error| format!("could not read the request: {}", error))
+	}
+
+	fn respond(&mut self, response: &RealmsProtocol) -> Result<(), String> {
+		self.send(response)
+	}
+
+	fn close(&mut self) {
+		self.stream.shutdown();
+	}
+}
+
+// the client's end of a pair of channels, for clients living in the same process as the universe
+pub struct ChannelTransport {
+	requests: Sender<(ClientId, RealmsProtocol)>,
+	responses: Receiver<RealmsProtocol>
+}
+
+pub struct ChannelConnection {
+	requests: Receiver<(ClientId, RealmsProtocol)>,
+	responses: Sender<RealmsProtocol>
+}
+
+pub fn channel() -> (ChannelTransport, ChannelConnection) {
+	let (request_sender, request_receiver) = mpsc::channel();
+	let (response_sender, response_receiver) = mpsc::channel();
+	(ChannelTransport { requests: request_sender, responses: response_receiver }, ChannelConnection { requests: request_receiver, responses: response_sender })
+}
+
+impl Transport for ChannelTransport {
+	fn exchange(&mut self, client: ClientId, request: &RealmsProtocol) -> Result<RealmsProtocol, String> {
+		self.requests.send((client, request.clone())).map_err(|_| "the universe is gone.".to_string())?;
+		self.responses.recv().map_err(|_| "the universe is gone.".to_string())
+	}
+}
+
+impl Connection for ChannelConnection {
+	fn receive(&mut self) -> Result<(ClientId, RealmsProtocol), String> {
+		self.requests.recv().map_err(|_| "the client is gone.".to_string())
+	}
+
+	fn respond(&mut self, response: &RealmsProtocol) -> Result<(), String> {
+		self.responses.send(response.clone()).map_err(|_| "the client is gone.".to_string())
+	}
+}
+
+impl Listener for TcpListener {
+	fn accept(&mut self) -> Result<Box<dyn Connection>, String> {
+		TcpListener::accept(self)
+			.map(|(stream, _)| Box::new(StreamTransport::new(stream)) as Box<dyn Connection>)
+			.map_err(|error| format!("could not accept a client: {}", error))
+	}
+}
+
+impl Listener for UnixListener {
+	fn accept(&mut self) -> Result<Box<dyn Connection>, String> {
+		UnixListener::accept(self)
+			.map(|(stream, _)| Box::new(StreamTransport::new(stream)) as Box<dyn Connection>)
+			.map_err(|error| format!("could not accept a client: {}", error))
+	}
+}
+
+// connects to a server at an address like 127.0.0.1:8080 or at a socket file like unix:/tmp/realms.sock
+pub fn connect(host: &str) -> Result<Box<dyn Transport>, String> {
+	let failed = |error| format!("could not connect to {}: {}", host, error);
+	if let Some(path) = host.strip_prefix(UNIX_PREFIX) {
+		UnixStream::connect(path)
+			.map(|stream| Box::new(StreamTransport::new(stream)) as Box<dyn Transport>)
+			.map_err(failed)
+	} else {
+		TcpStream::connect(host)
+			.map(|stream| Box::new(StreamTransport::new(stream)) as Box<dyn Transport>)
+			.map_err(failed)
+	}
+}
+
+// listens at an address or a socket file, named the same way as for connecting
+pub fn listen(host: &str) -> Result<Box<dyn Listener>, String> {
+	let failed = |error| format!("could not listen at {}: {}", host, error);
+	if let Some(path) = host.strip_prefix(UNIX_PREFIX) {
+		// a socket file nobody answers at is left over from a server gone
+		let socket = fs::metadata(path).map(|metadata| metadata.file_type().is_socket()).unwrap_or(false);
+		if socket && UnixStream::connect(path).is_err() {
+			let _ = fs::remove_file(path);
+		}
+		UnixListener::bind(path)
+			.map(|listener| Box::new(listener) as Box<dyn Listener>)
+			.map_err(failed)
+	} else {
+		TcpListener::bind(host)
+			.map(|listener| Box::new(listener) as Box<dyn Listener>)
+			.map_err(failed)
+	}
+}
@@ -26,7 +26,7 @@ impl<T: Clone> SelectionStorage<T> {
         }
     }
 
-    pub fn prev(&mut self) -> Option<&T> {
+    pub fn select_prev(&mut self) -> Option<&T> {
         if self.current_selection > 0 {
             self.current_selection -= 1;
         } else if self.storage.len() != 0 {
@@ -35,7 +35,7 @@ impl<T: Clone> SelectionStorage<T> {
         self.current()
     }
 
-    pub fn next(&mut self) -> Option<&T> {
+    pub fn select_next(&mut self) -> Option<&T> {
         if self.current_selection + 1 < self.storage.len() {
             self.current_selection += 1;
         } else {
@@ -122,6 +122,12 @@ impl<T: Clone> SelectionStorage<T> {
     }
 }
 
+impl<T: Clone> Default for SelectionStorage<T> {
+    fn default() -> SelectionStorage<T> {
+        SelectionStorage::new()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SelectionHashMap<T: Clone + Ord> {
     storage: BTreeMap<usize, T>,
@@ -136,7 +142,7 @@ impl<T: Clone + Ord> SelectionHashMap<T> {
         }
     }
 
-    pub fn prev(&mut self) -> Option<&T> {
+    pub fn select_prev(&mut self) -> Option<&T> {
         if self.current_selection > 0 {
             self.current_selection -= 1;
         } else if self.storage.len() != 0 {
@@ -145,7 +151,7 @@ impl<T: Clone + Ord> SelectionHashMap<T> {
         self.current()
     }
 
-    pub fn next(&mut self) -> Option<&T> {
+    pub fn select_next(&mut self) -> Option<&T> {
         if self.current_selection + 1 < self.storage.len() {
             self.current_selection += 1;
         } else {
@@ -211,4 +217,10 @@ impl<T: Clone + Ord> SelectionHashMap<T> {
     pub fn clear(&mut self) {
         self.storage.clear()
     }
+}
+
+impl<T: Clone + Ord> Default for SelectionHashMap<T> {
+    fn default() -> SelectionHashMap<T> {
+        SelectionHashMap::new()
+    }
 }
\ No newline at end of file
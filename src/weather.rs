@@ -0,0 +1,108 @@
+use rand::{thread_rng, distributions::Uniform, Rng};
+
+use tokens::*;
+use utility::*;
+use ecology;
+
+// realm ages a day lasts
+pub const DAY_LENGTH: usize = 4;
+// percent chance the weather of a region holds for another realm age
+const HOLDS: usize = 70;
+
+pub fn day(age: usize) -> usize {
+    age / DAY_LENGTH + 1
+}
+
+pub fn daytime(age: usize) -> Daytime {
+    match age % DAY_LENGTH {
+        0 => Daytime::Morning,
+        1 => Daytime::Noon,
+        2 => Daytime::Evening,
+        _ => Daytime::Night
+    }
+}
+
+// percent chances for rain, fog and storm when the weather turns, whatever is left is clear sky
+fn odds(region: &Region, season: Season) -> (usize, usize, usize) {
+    let (rain, fog, storm) = match season {
+        Season::Spring => (30, 10, 5),
+        Season::Summer => (10, 5, 10),
+        Season::Autumn => (25, 25, 15),
+        Season::Winter => (20, 20, 20)
+    };
+    match region.terrain {
+        // storms come in from the sea, fog lingers in the valleys
+        Terrain::Coast => (rain, fog, storm + 10),
+        Terrain::Forest => (rain, fog + 10, storm),
+        Terrain::Mountain => (rain + 10, fog, storm),
+        Terrain::Planes => (rain, fog, storm)
+    }
+}
+
+// lets the weather of every region turn or hold for one realm age.
+pub fn change(regions: &mut SelectionHashMap<Region>, age: usize) {
+    let season = ecology::season(age);
+    let mut rng = thread_rng();
+    for (_, region) in regions.iter_mut() {
+        if rng.sample(Uniform::new(0, 100)) < HOLDS {
+            continue;
+        }
+
+        let (rain, fog, storm) = odds(region, season);
+        let roll = rng.sample(Uniform::new(0, 100));
+        region.weather = if roll < rain {
+            Weather::Rain
+        } else if roll < rain + fog {
+            Weather::Fog
+        } else if roll < rain + fog + storm {
+            Weather::Storm
+        } else {
+            Weather::Clear
+        };
+    }
+}
+
+// how many rings of regions around the explorer's one can be made out
+pub fn sight(region: &Region, age: usize) -> usize {
+    let mut sight: usize = 1;
+    // a watchtower lets explorers see one region further
+    if region.has_building(Building::Watchtower) {
+        sight += 1;
+    }
+    if daytime(age) == Daytime::Night {
+        sight -= 1;
+    }
+    match region.weather {
+        Weather::Fog | Weather::Storm => sight.saturating_sub(1),
+        Weather::Clear | Weather::Rain => sight
+    }
+}
+
+// rain and storms swell the rivers, too wild to wade through
+pub fn flooded(region: &Region) -> bool {
+    region.has_particularity(Particularity::River) && (region.weather == Weather::Rain || region.weather == Weather::Storm)
+}
+
+// realm ages it takes to get into the region
+pub fn movement(region: &Region, age: usize) -> usize {
+    let mut ages = match region.weather {
+        Weather::Clear | Weather::Fog => 1,
+        Weather::Rain => 2,
+        Weather::Storm => 3
+    };
+    if daytime(age) == Daytime::Night {
+        ages += 1;
+    }
+    ages
+}
+
+// percent points taken off the chance of a voyage, a lighthouse guides through fog but not through a storm
+pub fn sailing(from: &Region, to: &Region) -> usize {
+    let hazard = |region: &Region| match region.weather {
+        Weather::Storm => 40,
+        Weather::Fog if !region.has_building(Building::Lighthouse) && !region.has_particularity(Particularity::Lighthouse) => 20,
+        Weather::Rain => 5,
+        _ => 0
+    };
+    hazard(from).max(hazard(to))
+}
@@ -0,0 +1,121 @@
+extern crate realms;
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::env;
+use std::process;
+
+use realms::tokens::*;
+use realms::client_api::RealmsClient;
+use realms::server::{self, LocalServer, Universe};
+use realms::transport;
+
+// an explorer still on the ship goes ashore at the first region the island shows
+fn land(client: &mut RealmsClient, realm: &Realm) -> Realm {
+	let explorer = realm.expedition.explorers.iter().next().expect("the expedition has no explorers").id;
+	let region = realm.island.regions.iter().map(|(id, _)| *id).min().expect("the island shows no region");
+	client.change_region(realm.id, region, explorer).expect("could not go ashore")
+}
+
+#[test]
+fn clients_play_realms_of_their_own() {
+	let server = LocalServer::start(Universe::new());
+	let mut first = RealmsClient::new(server.connect());
+	let mut second = RealmsClient::new(server.connect());
+
+	let first_id = first.register().unwrap();
+	let second_id = second.register().unwrap();
+	assert_ne!(first_id, second_id);
+
+	let realm = first.new_realm().unwrap();
+	let other = second.new_realm().unwrap();
+	assert_ne!(realm.id, other.id);
+
+	let realm = land(&mut first, &realm);
+	let explorer = realm.expedition.explorers.iter().next().unwrap();
+	assert!(explorer.region.is_some());
+
+	assert_eq!(first.realms().unwrap().iter().map(|summary| summary.id).collect::<Vec<RealmId>>(), vec![realm.id]);
+	assert_eq!(second.realms().unwrap().iter().map(|summary| summary.id).collect::<Vec<RealmId>>(), vec![other.id]);
+}
+
+#[test]
+fn moving_past_the_neighbors_is_rejected() {
+	let server = LocalServer::start(Universe::new());
+	let mut client = RealmsClient::new(server.connect());
+	client.register().unwrap();
+
+	let realm = client.new_realm().unwrap();
+	let realm = land(&mut client, &realm);
+	let explorer = realm.expedition.explorers.iter().next().unwrap();
+	let region = explorer.region.and_then(|id| realm.island.regions.storage().get(&id)).unwrap();
+	// the explorer only knows the regions around, the template has the whole island.
+	// neither blockades nor rivers are in the way, only the distance
+	let far = server.universe.lock().unwrap().realms[&realm.id].template.regions.iter()
+		.map(|(_, region)| region)
+		.find(|far| far.id != region.id && !region.neighbors.contains(&far.id) && far.blockade.is_none() && !far.has_particularity(Particularity::River))
+		.map(|far| far.id)
+		.expect("the island has no region beyond the neighbors");
+
+	assert!(client.change_region(realm.id, far, explorer.id).is_err());
+	assert_eq!(client.realm(realm.id).unwrap().age, realm.age);
+}
+
+#[test]
+fn realms_are_duplicated_and_deleted() {
+	let server = LocalServer::start(Universe::new());
+	let mut client = RealmsClient::new(server.connect());
+	client.register().unwrap();
+
+	let realm = client.new_realm().unwrap();
+	let realm = land(&mut client, &realm);
+
+	let realms = client.duplicate_realm(realm.id).unwrap();
+	assert_eq!(realms.iter().len(), 2);
+	let duplicate = realms.iter().map(|summary| summary.id).find(|id| *id != realm.id).unwrap();
+	assert_eq!(client.realm(duplicate).unwrap().age, realm.age);
+
+	let realms = client.delete_realm(realm.id).unwrap();
+	assert_eq!(realms.iter().map(|summary| summary.id).collect::<Vec<RealmId>>(), vec![duplicate]);
+
+	// ids of deleted realms are not given out again
+	let next = client.new_realm().unwrap();
+	assert!(next.id != realm.id && next.id != duplicate);
+}
+
+#[test]
+fn quitting_leaves_the_other_clients_playing() {
+	let server = LocalServer::start(Universe::new());
+	let mut first = RealmsClient::new(server.connect());
+	let mut second = RealmsClient::new(server.connect());
+	first.register().unwrap();
+	second.register().unwrap();
+
+	first.quit().unwrap();
+	assert!(first.realms().is_err());
+	assert!(second.new_realm().is_ok());
+}
+
+#[test]
+fn a_unix_socket_carries_the_protocol() {
+	let path = env::temp_dir().join(format!("realms-{}.sock", process::id()));
+	let host = format!("{}{}", transport::UNIX_PREFIX, path.display());
+
+	let mut listener = transport::listen(&host).unwrap();
+	let universe = Arc::new(Mutex::new(Universe::new()));
+	let served = Arc::clone(&universe);
+	let serving = thread::spawn(move || {
+		let connection = listener.accept().unwrap();
+		server::serve(&served, connection, None);
+	});
+
+	let mut client = RealmsClient::open(&host).unwrap();
+	let id = client.register().unwrap();
+	let realm = client.new_realm().unwrap();
+	assert_eq!(client.realm(realm.id).unwrap().id, realm.id);
+	client.quit().unwrap();
+
+	serving.join().unwrap();
+	assert!(universe.lock().unwrap().clients.contains_key(&id));
+	let _ = std::fs::remove_file(path);
+}